@@ -0,0 +1,132 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+
+/// A map which holds one value of each type, keyed by the type itself. This is handy as a context object which
+/// different parts of a program can store their own state in, without a struct listing every field up front.
+///
+/// ```rust
+/// use easy_collections::EasyAnyMap;
+///
+/// #[derive(Default)]
+/// struct Score(u32);
+///
+/// let mut context = EasyAnyMap::new();
+/// context.insert("player one");
+/// context.get_or_default::<Score>().0 += 10;
+/// assert_eq!(context.get::<&str>(), Some(&"player one"));
+/// assert_eq!(context.get::<Score>().unwrap().0, 10);
+/// assert!(context.get::<u8>().is_none());
+/// ```
+#[derive(Default)]
+pub struct EasyAnyMap {
+    inner: HashMap<TypeId, Box<dyn Any>>,
+}
+
+impl EasyAnyMap {
+    /// Create a new, empty `EasyAnyMap`.
+    pub fn new() -> EasyAnyMap {
+        EasyAnyMap::default()
+    }
+
+    /// Inserts `v` as the value for its type, returning the previous value of that type.
+    pub fn insert<T: Any>(&mut self, v: T) -> Option<T> {
+        self.inner
+            .insert(TypeId::of::<T>(), Box::new(v))
+            .map(|old| *old.downcast().unwrap())
+    }
+
+    /// Returns the value of type `T`, if there is one.
+    ///
+    /// Unlike `EasyMap`, there's no read-only fallback to a default value: the map would need to hold a default of
+    /// every type which might be read, and it can't create them behind a shared reference. Use
+    /// `EasyAnyMap::get_or_default` to insert the default, or `get::<T>().cloned().unwrap_or_default()` for a copy.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.inner
+            .get(&TypeId::of::<T>())
+            .map(|v| v.downcast_ref().unwrap())
+    }
+
+    /// Returns the value of type `T` mutably, if there is one.
+    pub fn get_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.inner
+            .get_mut(&TypeId::of::<T>())
+            .map(|v| v.downcast_mut().unwrap())
+    }
+
+    /// Returns the value of type `T` mutably, inserting `T::default()` first if there isn't one.
+    pub fn get_or_default<T: Any + Default>(&mut self) -> &mut T {
+        self.inner
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(T::default()))
+            .downcast_mut()
+            .unwrap()
+    }
+
+    /// Removes and returns the value of type `T`, if there is one.
+    pub fn remove<T: Any>(&mut self) -> Option<T> {
+        self.inner
+            .remove(&TypeId::of::<T>())
+            .map(|v| *v.downcast().unwrap())
+    }
+
+    /// Returns whether there's a value of type `T`.
+    pub fn contains<T: Any>(&self) -> bool {
+        self.inner.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the number of values in the map.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns whether the map has no values.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Removes every value from the map.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+}
+
+/// The values can't be printed, since they aren't required to implement `Debug`.
+impl Debug for EasyAnyMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EasyAnyMap")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn values() {
+        let mut map = EasyAnyMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert(1_u8), None);
+        assert_eq!(map.insert(2_u8), Some(1));
+        assert_eq!(map.insert(3_u16), None);
+        map.insert(String::from("a"));
+        map.get_mut::<String>().unwrap().push('b');
+        assert_eq!(map.get::<String>().map(String::as_str), Some("ab"));
+        assert_eq!(map.get::<u8>(), Some(&2));
+        assert_eq!(map.len(), 3);
+
+        map.get_or_default::<Vec<u8>>().push(1);
+        map.get_or_default::<Vec<u8>>().push(2);
+        assert_eq!(map.remove::<Vec<u8>>(), Some(vec![1, 2]));
+        assert_eq!(map.remove::<Vec<u8>>(), None);
+        assert!(map.contains::<u16>());
+        assert!(!map.contains::<u32>());
+        assert!(map.get_mut::<u32>().is_none());
+        assert_eq!(format!("{:?}", map), "EasyAnyMap { len: 3, .. }");
+
+        map.clear();
+        assert!(map.is_empty());
+    }
+}
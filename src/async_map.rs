@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use tokio::sync::OnceCell;
+
+/// A map for async code which caches the results of async functions, such as fetching a URL.
+///
+/// Concurrent requests for the same key are coalesced: while one task runs the function for a key, other tasks asking
+/// for that key wait for its result instead of running the function again. If the running task is cancelled, one of
+/// the waiting tasks runs the function instead.
+///
+/// ```rust
+/// use easy_collections::EasyAsyncMap;
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let cache = EasyAsyncMap::new();
+/// let page = cache.get_or_insert_with("/index", || async { String::from("<html>") }).await;
+/// assert_eq!(page, "<html>");
+/// // the function isn't called again for a key which is cached
+/// let page = cache.get_or_insert_with("/index", || async { unreachable!() }).await;
+/// assert_eq!(page, "<html>");
+/// # });
+/// ```
+pub struct EasyAsyncMap<K: Eq + Hash, V: Clone> {
+    cells: Mutex<HashMap<K, Arc<OnceCell<V>>>>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> EasyAsyncMap<K, V> {
+    /// Create a new, empty `EasyAsyncMap`.
+    pub fn new() -> EasyAsyncMap<K, V> {
+        EasyAsyncMap {
+            cells: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the cell for `k`, creating an empty one if there isn't one yet. The lock is only held while looking up
+    /// the cell, never while waiting for a value.
+    fn cell(&self, k: &K) -> Arc<OnceCell<V>> {
+        let mut cells = self.cells.lock().unwrap_or_else(PoisonError::into_inner);
+        match cells.get(k) {
+            Some(cell) => Arc::clone(cell),
+            None => Arc::clone(cells.entry(k.clone()).or_default()),
+        }
+    }
+
+    /// Returns the value for `k`, calling `f` and caching its result if it's not in the map yet.
+    pub async fn get_or_insert_with<F, Fut>(&self, k: K, f: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V>,
+    {
+        self.cell(&k).get_or_init(f).await.clone()
+    }
+
+    /// Returns the value for `k`, if it's in the map and isn't still being computed.
+    pub fn get(&self, k: &K) -> Option<V> {
+        let cells = self.cells.lock().unwrap_or_else(PoisonError::into_inner);
+        cells.get(k).and_then(|cell| cell.get().cloned())
+    }
+
+    /// Inserts a value for `k`, replacing any cached value. Tasks which are waiting for a value for `k` keep waiting
+    /// for the function they're waiting on.
+    pub fn insert(&self, k: K, v: V) {
+        let mut cells = self.cells.lock().unwrap_or_else(PoisonError::into_inner);
+        cells.insert(k, Arc::new(OnceCell::new_with(Some(v))));
+    }
+
+    /// Removes `k` from the map, returning its value if it had one. The next request for `k` calls its function again.
+    pub fn remove(&self, k: &K) -> Option<V> {
+        let mut cells = self.cells.lock().unwrap_or_else(PoisonError::into_inner);
+        cells.remove(k).and_then(|cell| cell.get().cloned())
+    }
+
+    /// Returns the number of values in the map, not counting any which are still being computed.
+    pub fn len(&self) -> usize {
+        let cells = self.cells.lock().unwrap_or_else(PoisonError::into_inner);
+        cells.values().filter(|cell| cell.initialized()).count()
+    }
+
+    /// Returns whether there are no values in the map.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Default for EasyAsyncMap<K, V> {
+    fn default() -> Self {
+        EasyAsyncMap::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn coalesces_requests() {
+        let map = Arc::new(EasyAsyncMap::new());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let tasks = (0..10)
+            .map(|i| {
+                let map = Arc::clone(&map);
+                let calls = Arc::clone(&calls);
+                tokio::spawn(async move {
+                    map.get_or_insert_with(i % 2, || async move {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        i % 2 * 100
+                    })
+                    .await
+                })
+            })
+            .collect::<Vec<_>>();
+        for (i, task) in tasks.into_iter().enumerate() {
+            assert_eq!(task.await.unwrap(), i % 2 * 100);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn cancelled() {
+        let map = EasyAsyncMap::new();
+        let slow = map.get_or_insert_with('a', std::future::pending);
+        assert!(tokio::time::timeout(Duration::from_millis(1), slow)
+            .await
+            .is_err());
+        assert_eq!(map.get(&'a'), None);
+        assert!(map.is_empty());
+        assert_eq!(map.get_or_insert_with('a', || async { 1 }).await, 1);
+    }
+
+    #[tokio::test]
+    async fn insert_remove() {
+        let map = EasyAsyncMap::new();
+        map.insert("a", 1);
+        assert_eq!(map.get(&"a"), Some(1));
+        assert_eq!(map.get_or_insert_with("a", || async { 2 }).await, 1);
+        assert_eq!(map.remove(&"a"), Some(1));
+        assert_eq!(map.remove(&"a"), None);
+        assert_eq!(map.get_or_insert_with("a", || async { 2 }).await, 2);
+    }
+}
@@ -0,0 +1,322 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    iter::FromIterator,
+    ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Deref, Index, Sub, SubAssign},
+};
+
+use paste::paste;
+
+#[macro_export]
+macro_rules! bag {
+    () => {
+        $crate::EasyBag::new()
+    };
+    {$($key:expr$(,)?)*} => {{
+        let mut bag = $crate::bag!{};
+        $(bag.insert($key);)*
+        bag
+    }};
+}
+
+/// A multiset, which counts how many times each item was inserted. Indexing it returns the count of an item, which is
+/// `0` for items which aren't in the bag.
+///
+/// It implements the same operators as Python's `Counter`: `|` takes the largest count of each item, `&` takes the
+/// smallest, `+` adds the counts and `-` subtracts them (dropping any which are zero or less).
+///
+/// ```rust
+/// use easy_collections::bag;
+///
+/// let a = &bag!{'a', 'a', 'a', 'b'};
+/// let b = &bag!{'a', 'b', 'b', 'c'};
+/// assert_eq!(a | b, bag!{'a', 'a', 'a', 'b', 'b', 'c'});
+/// assert_eq!(a & b, bag!{'a', 'b'});
+/// assert_eq!(a + b, bag!{'a', 'a', 'a', 'a', 'b', 'b', 'b', 'c'});
+/// assert_eq!(a - b, bag!{'a', 'a'});
+/// assert_eq!(a['a'], 3);
+/// assert_eq!(a['z'], 0);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EasyBag<K: Eq + Hash> {
+    pub(crate) counts: HashMap<K, usize>,
+}
+
+impl<K: Eq + Hash> EasyBag<K> {
+    /// Create a new, empty `EasyBag`.
+    pub fn new() -> EasyBag<K> {
+        EasyBag {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Adds one of `k` to the bag, returning its new count.
+    pub fn insert(&mut self, k: K) -> usize {
+        self.insert_n(k, 1)
+    }
+
+    /// Adds `n` of `k` to the bag, returning its new count.
+    pub fn insert_n(&mut self, k: K, n: usize) -> usize {
+        if n == 0 {
+            return self.count(&k);
+        }
+
+        let count = self.counts.entry(k).or_insert(0);
+        *count += n;
+        *count
+    }
+
+    /// Removes one of `k` from the bag, returning its new count.
+    pub fn remove(&mut self, k: &K) -> usize {
+        self.remove_n(k, 1)
+    }
+
+    /// Removes up to `n` of `k` from the bag, returning its new count.
+    pub fn remove_n(&mut self, k: &K, n: usize) -> usize {
+        match self.counts.get_mut(k) {
+            Some(count) if *count > n => {
+                *count -= n;
+                *count
+            }
+            Some(_) => {
+                self.counts.remove(k);
+                0
+            }
+            None => 0,
+        }
+    }
+
+    /// Returns how many of `k` are in the bag.
+    pub fn count(&self, k: &K) -> usize {
+        self.counts.get(k).copied().unwrap_or(0)
+    }
+
+    /// Returns whether there are at least `n` of `k` in the bag.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyBag;
+    ///
+    /// let letters = "hello".chars().collect::<EasyBag<_>>();
+    /// assert!(letters.contains_at_least(&'l', 2));
+    /// assert!(!letters.contains_at_least(&'l', 3));
+    /// assert!(letters.contains_at_least(&'z', 0));
+    /// ```
+    pub fn contains_at_least(&self, k: &K, n: usize) -> bool {
+        self.count(k) >= n
+    }
+
+    /// Returns the total number of items in the bag, including duplicates.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns an iterator which yields each item as many times as it's in the bag. The order of the items is arbitrary.
+    ///
+    /// ```rust
+    /// use easy_collections::bag;
+    ///
+    /// let bag = bag!{'a', 'b', 'a'};
+    /// let mut elements = bag.elements().collect::<Vec<_>>();
+    /// elements.sort();
+    /// assert_eq!(elements, vec![&'a', &'a', &'b']);
+    /// ```
+    pub fn elements(&self) -> impl Iterator<Item = &K> {
+        self.counts
+            .iter()
+            .flat_map(|(k, n)| std::iter::repeat_n(k, *n))
+    }
+}
+
+impl<K: Eq + Hash> Default for EasyBag<K> {
+    fn default() -> Self {
+        EasyBag::new()
+    }
+}
+
+impl<K: Eq + Hash> From<Vec<K>> for EasyBag<K> {
+    fn from(v: Vec<K>) -> Self {
+        v.into_iter().collect()
+    }
+}
+
+impl<K: Eq + Hash + Clone> From<&[K]> for EasyBag<K> {
+    fn from(v: &[K]) -> Self {
+        v.iter().cloned().collect()
+    }
+}
+
+impl<K: Eq + Hash> FromIterator<K> for EasyBag<K> {
+    fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
+        let mut bag = EasyBag::new();
+        bag.extend(iter);
+        bag
+    }
+}
+
+impl<K: Eq + Hash> Extend<K> for EasyBag<K> {
+    fn extend<T: IntoIterator<Item = K>>(&mut self, iter: T) {
+        for k in iter {
+            self.insert(k);
+        }
+    }
+}
+
+impl<K: Eq + Hash> IntoIterator for EasyBag<K> {
+    type Item = (K, usize);
+    type IntoIter = std::collections::hash_map::IntoIter<K, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+/// The counts can be read as a `HashMap`. There's no `DerefMut`, since the bag never stores a count of zero.
+impl<K: Eq + Hash> Deref for EasyBag<K> {
+    type Target = HashMap<K, usize>;
+    fn deref(&self) -> &Self::Target {
+        &self.counts
+    }
+}
+
+impl<K: Eq + Hash> Index<K> for EasyBag<K> {
+    type Output = usize;
+    fn index(&self, key: K) -> &Self::Output {
+        self.counts.get(&key).unwrap_or(&0)
+    }
+}
+
+macro_rules! impl_bag_op {
+    ($trait:ident, $method:ident, |$this:ident, $rhs:ident| $assign:expr) => {
+        paste! {
+            impl<K: Eq + Hash + Clone, T: Into<EasyBag<K>>> $trait<T> for &EasyBag<K> {
+                type Output = EasyBag<K>;
+                fn $method(self, rhs: T) -> Self::Output {
+                    self.$method(&rhs.into())
+                }
+            }
+            impl<K: Eq + Hash + Clone, T: Into<EasyBag<K>>> $trait<T> for EasyBag<K> {
+                type Output = Self;
+                fn $method(mut self, rhs: T) -> Self::Output {
+                    self.[<$method _assign>](&rhs.into());
+                    self
+                }
+            }
+            impl<K: Eq + Hash + Clone, T: Into<EasyBag<K>>> [<$trait Assign>]<T> for EasyBag<K> {
+                fn [<$method _assign>](&mut self, rhs: T) {
+                    self.[<$method _assign>](&rhs.into())
+                }
+            }
+            // borrowed bags are used directly, rather than being converted
+            impl<K: Eq + Hash + Clone> $trait<&EasyBag<K>> for &EasyBag<K> {
+                type Output = EasyBag<K>;
+                fn $method(self, rhs: &EasyBag<K>) -> Self::Output {
+                    self.clone().$method(rhs)
+                }
+            }
+            impl<K: Eq + Hash + Clone> $trait<&EasyBag<K>> for EasyBag<K> {
+                type Output = Self;
+                fn $method(mut self, rhs: &EasyBag<K>) -> Self::Output {
+                    self.[<$method _assign>](rhs);
+                    self
+                }
+            }
+            impl<K: Eq + Hash + Clone> [<$trait Assign>]<&EasyBag<K>> for EasyBag<K> {
+                fn [<$method _assign>](&mut self, $rhs: &EasyBag<K>) {
+                    let $this = self;
+                    $assign
+                }
+            }
+        }
+    };
+}
+
+impl_bag_op! {
+    BitOr, bitor,
+    |this, rhs| for (k, &n) in &rhs.counts {
+        let count = this.counts.entry(k.clone()).or_insert(0);
+        *count = n.max(*count);
+    }
+}
+impl_bag_op! {
+    BitAnd, bitand,
+    |this, rhs| this.counts.retain(|k, count| {
+        *count = rhs.count(k).min(*count);
+        *count > 0
+    })
+}
+impl_bag_op! {
+    Add, add,
+    |this, rhs| for (k, &n) in &rhs.counts {
+        this.insert_n(k.clone(), n);
+    }
+}
+impl_bag_op! {
+    Sub, sub,
+    |this, rhs| for (k, &n) in &rhs.counts {
+        this.remove_n(k, n);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn macros() {
+        let bag: EasyBag<u8> = bag! {};
+        assert!(bag.is_empty());
+        let bag = bag! {1, 1, 2};
+        assert_eq!(bag[1], 2);
+        assert_eq!(bag[2], 1);
+        assert_eq!(bag.total(), 3);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut bag = EasyBag::new();
+        assert_eq!(bag.insert("a"), 1);
+        assert_eq!(bag.insert_n("a", 3), 4);
+        assert_eq!(bag.insert_n("b", 0), 0);
+        assert!(!bag.contains_key("b"));
+
+        assert_eq!(bag.remove(&"a"), 3);
+        assert_eq!(bag.remove_n(&"a", 2), 1);
+        assert_eq!(bag.remove_n(&"a", 10), 0);
+        assert_eq!(bag.remove(&"a"), 0);
+        assert!(bag.is_empty());
+    }
+
+    #[test]
+    fn ops() {
+        let a = bag! {1, 1, 2, 3};
+        let b = bag! {1, 2, 2, 4};
+
+        assert_eq!(&a | &b, bag! {1, 1, 2, 2, 3, 4});
+        assert_eq!(&a & &b, bag! {1, 2});
+        assert_eq!(&a + &b, bag! {1, 1, 1, 2, 2, 2, 3, 4});
+        assert_eq!(&a - &b, bag! {1, 3});
+        assert_eq!(&b - &a, bag! {2, 4});
+        assert_eq!(a.clone() - vec![1, 1, 1], bag! {2, 3});
+        assert_eq!(a.clone() & [3, 3].as_ref(), bag! {3});
+
+        let mut c = a.clone();
+        c += &b;
+        c -= vec![1, 1, 1];
+        assert_eq!(c, bag! {2, 2, 2, 3, 4});
+        c &= &b;
+        assert_eq!(c, bag! {2, 2, 4});
+        c |= a;
+        assert_eq!(c, bag! {1, 1, 2, 2, 3, 4});
+    }
+
+    #[test]
+    fn elements() {
+        let mut bag = "banana".chars().collect::<EasyBag<_>>();
+        assert_eq!(bag.elements().count(), bag.total());
+        bag.remove_n(&'a', 3);
+        let mut elements = bag.elements().copied().collect::<Vec<_>>();
+        elements.sort();
+        assert_eq!(elements, vec!['b', 'n', 'n']);
+        assert_eq!(EasyBag::<u8>::new().elements().next(), None);
+    }
+}
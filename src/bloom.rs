@@ -0,0 +1,148 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use crate::EasySet;
+
+/// The false positive rate used by `EasyBloom::from`.
+const DEFAULT_FALSE_POSITIVE_RATE: f64 = 0.01;
+
+/// A Bloom filter, which remembers which items were inserted using far less memory than a set, at the cost of
+/// sometimes claiming items were inserted when they weren't. Items which were inserted are always found.
+///
+/// ```rust
+/// use easy_collections::EasyBloom;
+///
+/// let mut seen = EasyBloom::new(1000, 0.01);
+/// for n in 0..1000 {
+///     seen.insert(&n);
+/// }
+/// assert!((0..1000).all(|n| seen.maybe_contains(&n)));
+/// let false_positives = (1000..2000).filter(|n| seen.maybe_contains(n)).count();
+/// assert!(false_positives < 50);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EasyBloom<K: Hash> {
+    bits: Vec<u64>,
+    bit_count: usize,
+    hash_count: usize,
+    _marker: PhantomData<fn(&K)>,
+}
+
+impl<K: Hash> EasyBloom<K> {
+    /// Create a new, empty `EasyBloom` sized for `expected_items` items, so that once they're inserted, checking for an
+    /// item which wasn't inserted gives a false positive with a probability of about `false_positive_rate`.
+    ///
+    /// Panics if `false_positive_rate` isn't between `0` and `1` (exclusive).
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> EasyBloom<K> {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "the false positive rate must be between 0 and 1, but it was {}",
+            false_positive_rate
+        );
+
+        let n = expected_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let bit_count = ((-n * false_positive_rate.ln() / (ln2 * ln2)).ceil() as usize).max(64);
+        let hash_count = ((bit_count as f64 / n * ln2).round() as usize).max(1);
+        EasyBloom {
+            bits: vec![0; bit_count.div_ceil(64)],
+            bit_count,
+            hash_count,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the positions of the bits for `k`, using double hashing.
+    fn positions(&self, k: &K) -> impl Iterator<Item = usize> + 'static {
+        let hash = |seed: u8| {
+            let mut hasher = DefaultHasher::new();
+            seed.hash(&mut hasher);
+            k.hash(&mut hasher);
+            hasher.finish()
+        };
+        let (h1, h2) = (hash(0), hash(1) | 1);
+        let bit_count = self.bit_count as u64;
+        (0..self.hash_count as u64)
+            .map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % bit_count) as usize)
+    }
+
+    /// Adds `k` to the filter.
+    pub fn insert(&mut self, k: &K) {
+        for i in self.positions(k) {
+            self.bits[i / 64] |= 1 << (i % 64);
+        }
+    }
+
+    /// Returns `false` if `k` was definitely never inserted, and `true` if it probably was.
+    pub fn maybe_contains(&self, k: &K) -> bool {
+        self.positions(k)
+            .all(|i| self.bits[i / 64] & (1 << (i % 64)) != 0)
+    }
+
+    /// Returns the number of bits the filter uses.
+    pub fn bit_count(&self) -> usize {
+        self.bit_count
+    }
+
+    /// Returns the number of bits which are set for each item.
+    pub fn hash_count(&self) -> usize {
+        self.hash_count
+    }
+}
+
+impl<K: Hash> Extend<K> for EasyBloom<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for k in iter {
+            self.insert(&k);
+        }
+    }
+}
+
+/// Creates a filter containing every item in the set, sized for the set with a false positive rate of 1%.
+impl<K: Eq + Hash> From<&EasySet<K>> for EasyBloom<K> {
+    fn from(set: &EasySet<K>) -> Self {
+        let mut bloom = EasyBloom::new(set.len(), DEFAULT_FALSE_POSITIVE_RATE);
+        for k in set {
+            bloom.insert(k);
+        }
+
+        bloom
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::set;
+
+    #[test]
+    fn no_false_negatives() {
+        let set = (0..10_000).map(|n| n.to_string()).collect::<EasySet<_>>();
+        let bloom = EasyBloom::from(&set);
+        assert!(set.iter().all(|k| bloom.maybe_contains(k)));
+        let false_positives = (10_000..20_000)
+            .filter(|n| bloom.maybe_contains(&n.to_string()))
+            .count();
+        assert!(false_positives < 200, "{}", false_positives);
+    }
+
+    #[test]
+    fn sizing() {
+        let bloom = EasyBloom::<u8>::new(1000, 0.01);
+        assert_eq!(bloom.bit_count(), 9586);
+        assert_eq!(bloom.hash_count(), 7);
+        let empty = EasyBloom::<u8>::from(&set! {});
+        assert!(!empty.maybe_contains(&0));
+
+        let mut bloom = EasyBloom::new(0, 0.5);
+        bloom.extend(vec!['a', 'b']);
+        assert!(bloom.maybe_contains(&'a') && bloom.maybe_contains(&'b'));
+    }
+
+    #[test]
+    #[should_panic(expected = "the false positive rate must be between 0 and 1, but it was 1")]
+    fn invalid_rate() {
+        EasyBloom::<u8>::new(10, 1.0);
+    }
+}
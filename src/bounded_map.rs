@@ -0,0 +1,391 @@
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Debug};
+use std::hash::{BuildHasher, Hash};
+use std::ops::{Index, IndexMut};
+
+/// Which entry an `EasyBoundedMap` removes when it's full and a new key is inserted.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EvictionPolicy {
+    /// Removes the entry which was inserted first.
+    Fifo,
+    /// Removes the entry which was used least recently.
+    Lru,
+    /// Removes the entry which was used the fewest times, or the least recently used of those.
+    Lfu,
+    /// Removes a random entry.
+    Random,
+}
+
+/// An entry of an `EasyBoundedMap`, along with what's needed to decide whether to evict it.
+struct Slot<V> {
+    value: V,
+    inserted: u64,
+    used: u64,
+    uses: u64,
+}
+
+type EvictFn<'a, K, V> = dyn FnMut(K, V) + 'a;
+
+/// A map which holds at most `capacity` entries, for caches which need to be limited in size. When it's full,
+/// inserting a new key removes an existing entry, chosen by its `EvictionPolicy`. Reading keys which aren't in the map
+/// returns a default value, the same as `EasyMap`.
+///
+/// The entries are also kept in the order they'd be evicted in, so evicting and using entries takes `O(log n)` time,
+/// except for `EvictionPolicy::Random` which looks through the entries to pick one.
+///
+/// The lifetime lets the eviction callback borrow local variables. A map without a callback, or with one which only
+/// owns its data, can be stored as an `EasyBoundedMap<'static, K, V>`.
+///
+/// ```rust
+/// use easy_collections::{EasyBoundedMap, EvictionPolicy};
+///
+/// let mut evicted = vec![];
+/// let mut cache = EasyBoundedMap::new(2, EvictionPolicy::Lru).on_evict(|k, _| evicted.push(k));
+/// cache.insert("a", 1);
+/// cache.insert("b", 2);
+/// cache.get(&"a");
+/// cache.insert("c", 3);
+/// assert_eq!(cache.len(), 2);
+/// assert_eq!(cache["b"], 0);
+/// drop(cache);
+/// assert_eq!(evicted, vec!["b"]);
+/// ```
+pub struct EasyBoundedMap<'a, K: Eq + Hash, V: Clone> {
+    entries: HashMap<K, Slot<V>>,
+    capacity: usize,
+    policy: EvictionPolicy,
+    default: V,
+    // the keys by their rank (see `EasyBoundedMap::rank`), so the next entry to evict is the first one
+    order: BTreeMap<(u64, u64), K>,
+    tick: u64,
+    rng: u64,
+    on_evict: Option<Box<EvictFn<'a, K, V>>>,
+}
+
+impl<'a, K: Eq + Hash + Clone, V: Clone + Default> EasyBoundedMap<'a, K, V> {
+    /// Create a new `EasyBoundedMap`. The value `V` must implement `Default`.
+    ///
+    /// Panics if `capacity` is `0`.
+    pub fn new(capacity: usize, policy: EvictionPolicy) -> EasyBoundedMap<'a, K, V> {
+        EasyBoundedMap::new_with_default(capacity, policy, V::default())
+    }
+}
+
+impl<'a, K: Eq + Hash + Clone, V: Clone> EasyBoundedMap<'a, K, V> {
+    /// Create a new `EasyBoundedMap`. The value `V` does not need to implement `Default`, instead you provide it with
+    /// one here.
+    ///
+    /// Panics if `capacity` is `0`.
+    pub fn new_with_default(
+        capacity: usize,
+        policy: EvictionPolicy,
+        default: V,
+    ) -> EasyBoundedMap<'a, K, V> {
+        assert!(
+            capacity > 0,
+            "the capacity of an EasyBoundedMap must be at least 1"
+        );
+        EasyBoundedMap {
+            entries: HashMap::new(),
+            capacity,
+            policy,
+            default,
+            order: BTreeMap::new(),
+            tick: 0,
+            rng: RandomState::new().hash_one(0_u8) | 1,
+            on_evict: None,
+        }
+    }
+
+    /// Returns the map with a callback, which is called with each entry that's evicted. It isn't called for entries
+    /// which are removed with `EasyBoundedMap::remove`.
+    pub fn on_evict(mut self, f: impl FnMut(K, V) + 'a) -> EasyBoundedMap<'a, K, V> {
+        self.on_evict = Some(Box::new(f));
+        self
+    }
+
+    /// Returns the maximum number of entries the map holds.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the eviction policy of the map.
+    pub fn policy(&self) -> EvictionPolicy {
+        self.policy
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Same as `HashMap::contains_key`. This doesn't count as using the entry.
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.entries.contains_key(k)
+    }
+
+    /// Returns an iterator over the entries of the map, in an arbitrary order. This doesn't count as using them.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(k, slot)| (k, &slot.value))
+    }
+
+    /// Returns the next tick, which orders insertions and uses.
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Returns the next number from a xorshift generator, for random eviction.
+    fn next_random(&mut self) -> u64 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        self.rng
+    }
+
+    /// Returns where the entry comes in the eviction order, the lowest rank being evicted first. Ticks are unique, so
+    /// no two entries have the same rank.
+    fn rank(policy: EvictionPolicy, slot: &Slot<V>) -> (u64, u64) {
+        match policy {
+            EvictionPolicy::Fifo | EvictionPolicy::Random => (0, slot.inserted),
+            EvictionPolicy::Lru => (0, slot.used),
+            EvictionPolicy::Lfu => (slot.uses, slot.used),
+        }
+    }
+
+    /// Removes an entry chosen by the eviction policy, and passes it to the callback.
+    fn evict(&mut self) {
+        let victim = match self.policy {
+            EvictionPolicy::Random => {
+                let n = (self.next_random() % self.entries.len() as u64) as usize;
+                self.entries.keys().nth(n).cloned()
+            }
+            _ => self.order.values().next().cloned(),
+        };
+
+        if let Some(k) = victim {
+            let value = self.remove(&k).unwrap();
+            if let Some(f) = &mut self.on_evict {
+                f(k, value);
+            }
+        }
+    }
+
+    /// Marks the entry for `k` as used, and returns it.
+    fn touch(&mut self, k: &K) -> Option<&mut Slot<V>> {
+        let tick = self.next_tick();
+        let slot = self.entries.get_mut(k)?;
+        let key = self.order.remove(&Self::rank(self.policy, slot)).unwrap();
+        slot.used = tick;
+        slot.uses += 1;
+        self.order.insert(Self::rank(self.policy, slot), key);
+        Some(slot)
+    }
+
+    /// Inserts `v` for `k`, returning the previous value. If `k` is a new key and the map is full, an entry is evicted
+    /// first. Inserting counts as using the entry.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        if let Some(slot) = self.touch(&k) {
+            return Some(std::mem::replace(&mut slot.value, v));
+        }
+
+        self.insert_new(k, v);
+        None
+    }
+
+    /// Inserts `v` for `k`, which isn't in the map, evicting an entry first if the map is full. This counts as the
+    /// first use of the entry.
+    fn insert_new(&mut self, k: K, v: V) -> &mut V {
+        if self.entries.len() >= self.capacity {
+            self.evict();
+        }
+
+        let tick = self.next_tick();
+        let slot = Slot {
+            value: v,
+            inserted: tick,
+            used: tick,
+            uses: 1,
+        };
+        self.order.insert(Self::rank(self.policy, &slot), k.clone());
+        &mut self.entries.entry(k).or_insert(slot).value
+    }
+
+    /// Returns the value for `k`, counting it as a use of the entry.
+    pub fn get(&mut self, k: &K) -> Option<&V> {
+        self.touch(k).map(|slot| &slot.value)
+    }
+
+    /// Returns a mutable reference to the value for `k`, counting it as a use of the entry.
+    pub fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        self.touch(k).map(|slot| &mut slot.value)
+    }
+
+    /// Returns the value for `k`, without counting it as a use of the entry.
+    pub fn peek(&self, k: &K) -> Option<&V> {
+        self.entries.get(k).map(|slot| &slot.value)
+    }
+
+    /// Same as `HashMap::remove`.
+    pub fn remove(&mut self, k: &K) -> Option<V> {
+        let slot = self.entries.remove(k)?;
+        self.order.remove(&Self::rank(self.policy, &slot));
+        Some(slot.value)
+    }
+}
+
+impl<K: Eq + Hash + Debug, V: Clone + Debug> Debug for EasyBoundedMap<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().map(|(k, slot)| (k, &slot.value)))
+            .finish()
+    }
+}
+
+/// Reading by index doesn't count as using the entry, since it doesn't borrow the map mutably.
+impl<K: Eq + Hash + Clone, V: Clone> Index<K> for EasyBoundedMap<'_, K, V> {
+    type Output = V;
+    fn index(&self, key: K) -> &Self::Output {
+        self.peek(&key).unwrap_or(&self.default)
+    }
+}
+
+/// Writing by index inserts the default value if `key` isn't in the map (which may evict an entry), and counts as
+/// using the entry.
+impl<K: Eq + Hash + Clone, V: Clone> IndexMut<K> for EasyBoundedMap<'_, K, V> {
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        if !self.entries.contains_key(&key) {
+            let default = self.default.clone();
+            return self.insert_new(key, default);
+        }
+
+        self.get_mut(&key).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn keys<K: Eq + Hash + Clone + Ord, V: Clone>(map: &EasyBoundedMap<'_, K, V>) -> Vec<K> {
+        let mut keys = map.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn fifo() {
+        let mut map = EasyBoundedMap::new(2, EvictionPolicy::Fifo);
+        map.insert(1, 'a');
+        map.insert(2, 'b');
+        map.get(&1);
+        assert_eq!(map.insert(1, 'c'), Some('a'));
+        map.insert(3, 'd');
+        assert_eq!(keys(&map), vec![2, 3]);
+    }
+
+    #[test]
+    fn lru() {
+        let mut map = EasyBoundedMap::new(2, EvictionPolicy::Lru);
+        map[1] = 'a';
+        map[2] = 'b';
+        map.get_mut(&1).unwrap().make_ascii_uppercase();
+        map.insert(3, 'c');
+        assert_eq!(keys(&map), vec![1, 3]);
+        assert_eq!(map.peek(&1), Some(&'A'));
+        // peeking and reading by index don't count as uses
+        assert_eq!(map[3], 'c');
+        map.insert(4, 'd');
+        assert_eq!(keys(&map), vec![3, 4]);
+    }
+
+    #[test]
+    fn lfu() {
+        let mut map = EasyBoundedMap::new(3, EvictionPolicy::Lfu);
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+        map.get(&"a");
+        map.get(&"a");
+        map.get(&"c");
+        map.insert("d", 4);
+        assert_eq!(keys(&map), vec!["a", "c", "d"]);
+        map.insert("e", 5);
+        assert_eq!(keys(&map), vec!["a", "c", "e"]);
+    }
+
+    #[test]
+    fn random() {
+        let mut evicted = 0;
+        let mut map = EasyBoundedMap::new(10, EvictionPolicy::Random).on_evict(|_, _| evicted += 1);
+        for n in 0..100 {
+            map[n] += 1;
+        }
+        assert_eq!(map.len(), 10);
+        assert_eq!(map.capacity(), 10);
+        assert_eq!(map.policy(), EvictionPolicy::Random);
+        assert_eq!(map.remove(&99), Some(1));
+        assert!(!map.contains_key(&99));
+        drop(map);
+        assert_eq!(evicted, 90);
+    }
+
+    #[test]
+    fn callback() {
+        let mut evicted = vec![];
+        let mut map = EasyBoundedMap::new_with_default(1, EvictionPolicy::Fifo, 0)
+            .on_evict(|k, v| evicted.push((k, v)));
+        map.insert('a', 1);
+        map.insert('b', 2);
+        map.remove(&'b');
+        map.insert('c', 3);
+        assert_eq!(map['z'], 0);
+        assert_eq!(format!("{:?}", map), "{'c': 3}");
+        drop(map);
+        assert_eq!(evicted, vec![('a', 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the capacity of an EasyBoundedMap must be at least 1")]
+    fn zero_capacity() {
+        EasyBoundedMap::<u8, u8>::new(0, EvictionPolicy::Lru);
+    }
+
+    #[test]
+    fn eviction_order_after_removing() {
+        let mut map = EasyBoundedMap::new(3, EvictionPolicy::Lru);
+        for n in 0..3 {
+            map.insert(n, n);
+        }
+        map.get(&0);
+        assert_eq!(map.remove(&1), Some(1));
+        map.insert(3, 3);
+        map.insert(4, 4);
+        assert_eq!(keys(&map), vec![0, 3, 4]);
+        assert_eq!(map.order.len(), map.len());
+        map.insert(5, 5);
+        assert_eq!(keys(&map), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn lfu_index_writes() {
+        let mut map = EasyBoundedMap::new(2, EvictionPolicy::Lfu);
+        // entries created by index writes and by inserts have both been used once
+        map[1] = 'a';
+        map.insert(2, 'b');
+        map.insert(3, 'c');
+        assert_eq!(keys(&map), vec![2, 3]);
+
+        map[2] = 'd';
+        map[4] = 'e';
+        assert_eq!(keys(&map), vec![2, 4]);
+        map.insert(5, 'f');
+        assert_eq!(keys(&map), vec![2, 5]);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::map::Fill;
+use crate::{EasyMap, EasySet};
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
+impl<K: Eq + Hash + Serialize, V: Serialize> EasyMap<K, V> {
+    /// Serializes the map (including its default value) into a compact binary format with `postcard`.
+    ///
+    /// ```rust
+    /// use easy_collections::{map, EasyMap};
+    ///
+    /// let map = map!{42; 1 => 10, 2 => 20};
+    /// let bytes = map.to_bytes().unwrap();
+    /// let restored = EasyMap::from_bytes(&bytes).unwrap();
+    /// assert_eq!(map, restored);
+    /// assert_eq!(restored[3], 42);
+    /// ```
+    pub fn to_bytes(&self) -> postcard::Result<Vec<u8>> {
+        postcard::to_allocvec(&(&self.default, &self.inner))
+    }
+}
+
+impl<'de, K: Eq + Hash + Deserialize<'de>, V: Clone + Deserialize<'de>> EasyMap<K, V> {
+    /// Deserializes a map created by `EasyMap::to_bytes`.
+    pub fn from_bytes(bytes: &'de [u8]) -> postcard::Result<EasyMap<K, V>> {
+        let (default, inner) = postcard::from_bytes(bytes)?;
+        Ok(EasyMap {
+            inner,
+            default,
+            fill: Fill::Value(V::clone),
+        })
+    }
+}
+
+impl<K: Eq + Hash + Serialize> EasySet<K> {
+    /// Serializes the set into a compact binary format with `postcard`.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasySet};
+    ///
+    /// let set = set!{1, 2, 3};
+    /// let bytes = set.to_bytes().unwrap();
+    /// assert_eq!(EasySet::from_bytes(&bytes), Ok(set));
+    /// ```
+    pub fn to_bytes(&self) -> postcard::Result<Vec<u8>> {
+        postcard::to_allocvec(&self.inner)
+    }
+}
+
+impl<'de, K: Eq + Hash + Deserialize<'de>> EasySet<K> {
+    /// Deserializes a set created by `EasySet::to_bytes`.
+    pub fn from_bytes(bytes: &'de [u8]) -> postcard::Result<EasySet<K>> {
+        Ok(EasySet {
+            inner: postcard::from_bytes(bytes)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{map, set};
+
+    #[test]
+    fn map_round_trip() {
+        let map =
+            map! {vec!['?']; "foo".to_string() => vec!['a', 'b'], "bar".to_string() => vec![]};
+        let bytes = map.to_bytes().unwrap();
+        assert_eq!(EasyMap::from_bytes(&bytes), Ok(map));
+
+        // borrowed data
+        let map = map! {"nope"; 1_u8 => "foo"};
+        let bytes = map.to_bytes().unwrap();
+        let map: EasyMap<u8, &str> = EasyMap::from_bytes(&bytes).unwrap();
+        assert_eq!(map[1], "foo");
+        assert_eq!(map[2], "nope");
+    }
+
+    #[test]
+    fn set_round_trip() {
+        let set = set! {'a', 'b', 'c'};
+        let bytes = set.to_bytes().unwrap();
+        assert_eq!(EasySet::from_bytes(&bytes), Ok(set));
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        assert!(EasyMap::<u8, u8>::from_bytes(&[1, 2]).is_err());
+        assert!(EasySet::<String>::from_bytes(&[1]).is_err());
+    }
+}
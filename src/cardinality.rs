@@ -0,0 +1,229 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{BitOr, BitOrAssign};
+
+use crate::EasySet;
+
+/// The precision used by `EasyCardinality::new`, which gives an error of about 0.8%.
+const DEFAULT_PRECISION: u8 = 14;
+
+/// A HyperLogLog estimator, which counts the distinct items inserted into it using a small, fixed amount of memory,
+/// at the cost of the count being approximate.
+///
+/// Estimators can be merged with `|`, which estimates the number of distinct items inserted into either of them.
+///
+/// ```rust
+/// use easy_collections::EasyCardinality;
+///
+/// let mut evens = EasyCardinality::new();
+/// let mut odds = EasyCardinality::new();
+/// for n in 0..100_000 {
+///     if n % 2 == 0 { evens.insert(&n) } else { odds.insert(&n) }
+///     evens.insert(&0);
+/// }
+/// let close = |estimate: usize, actual: usize| estimate.abs_diff(actual) < actual / 20;
+/// assert!(close(evens.estimate(), 50_000));
+/// assert!(close((&evens | &odds).estimate(), 100_000));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EasyCardinality<K: Hash> {
+    registers: Vec<u8>,
+    precision: u8,
+    _marker: PhantomData<fn(&K)>,
+}
+
+impl<K: Hash> EasyCardinality<K> {
+    /// Create a new, empty `EasyCardinality`, with an error of about 0.8%.
+    pub fn new() -> EasyCardinality<K> {
+        EasyCardinality::with_precision(DEFAULT_PRECISION)
+    }
+
+    /// Create a new, empty `EasyCardinality` which uses `2^precision` bytes of memory. Its error is about
+    /// `1.04 / sqrt(2^precision)`.
+    ///
+    /// Panics if `precision` isn't between `4` and `16` (inclusive).
+    pub fn with_precision(precision: u8) -> EasyCardinality<K> {
+        assert!(
+            (4..=16).contains(&precision),
+            "the precision must be between 4 and 16, but it was {}",
+            precision
+        );
+
+        EasyCardinality {
+            registers: vec![0; 1 << precision],
+            precision,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Adds `k` to the estimator.
+    pub fn insert(&mut self, k: &K) {
+        let mut hasher = DefaultHasher::new();
+        k.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let p = self.precision as u32;
+        let index = (hash >> (64 - p)) as usize;
+        let rank = ((hash << p).leading_zeros() + 1).min(64 - p + 1) as u8;
+        let register = &mut self.registers[index];
+        *register = (*register).max(rank);
+    }
+
+    /// Returns the estimated number of distinct items which were inserted.
+    pub fn estimate(&self) -> usize {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum = self
+            .registers
+            .iter()
+            .map(|r| 2f64.powi(-(*r as i32)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+
+        // for small counts, linear counting of the empty registers is more accurate
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as usize
+        } else {
+            estimate.round() as usize
+        }
+    }
+
+    /// Returns the precision of the estimator, see `EasyCardinality::with_precision`.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+}
+
+impl<K: Hash> Default for EasyCardinality<K> {
+    fn default() -> Self {
+        EasyCardinality::new()
+    }
+}
+
+impl<K: Hash> Extend<K> for EasyCardinality<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for k in iter {
+            self.insert(&k);
+        }
+    }
+}
+
+impl<K: Eq + Hash> From<&EasySet<K>> for EasyCardinality<K> {
+    fn from(set: &EasySet<K>) -> Self {
+        let mut estimator = EasyCardinality::new();
+        for k in set {
+            estimator.insert(k);
+        }
+
+        estimator
+    }
+}
+
+/// Panics if the estimators have different precisions.
+impl<K: Hash> BitOrAssign<&EasyCardinality<K>> for EasyCardinality<K> {
+    fn bitor_assign(&mut self, rhs: &EasyCardinality<K>) {
+        assert_eq!(
+            self.precision, rhs.precision,
+            "estimators with different precisions can't be merged"
+        );
+
+        for (r, other) in self.registers.iter_mut().zip(&rhs.registers) {
+            *r = (*r).max(*other);
+        }
+    }
+}
+impl<K: Hash> BitOr<&EasyCardinality<K>> for EasyCardinality<K> {
+    type Output = EasyCardinality<K>;
+    fn bitor(mut self, rhs: &EasyCardinality<K>) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl<K: Hash> BitOr<&EasyCardinality<K>> for &EasyCardinality<K> {
+    type Output = EasyCardinality<K>;
+    fn bitor(self, rhs: &EasyCardinality<K>) -> Self::Output {
+        let estimator = EasyCardinality {
+            registers: self.registers.clone(),
+            precision: self.precision,
+            _marker: PhantomData,
+        };
+
+        estimator | rhs
+    }
+}
+
+impl<K: Eq + Hash> EasySet<K> {
+    /// Estimates the size of the union of two sets with `EasyCardinality`, without building the union.
+    ///
+    /// ```rust
+    /// use easy_collections::EasySet;
+    ///
+    /// let a = (0..1000).collect::<EasySet<_>>();
+    /// let b = (500..1500).collect::<EasySet<_>>();
+    /// assert!(a.estimate_union_size(&b).abs_diff(1500) < 50);
+    /// ```
+    pub fn estimate_union_size(&self, other: &EasySet<K>) -> usize {
+        (EasyCardinality::from(self) | &EasyCardinality::from(other)).estimate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn within(estimate: usize, actual: usize, percent: usize) -> bool {
+        estimate.abs_diff(actual) * 100 <= actual * percent
+    }
+
+    #[test]
+    fn estimate() {
+        let mut estimator = EasyCardinality::new();
+        assert_eq!(estimator.estimate(), 0);
+        estimator.extend(vec!["a", "b", "a", "c"]);
+        assert_eq!(estimator.estimate(), 3);
+
+        for actual in [1_000, 10_000, 200_000] {
+            let mut estimator = EasyCardinality::new();
+            for n in 0..actual {
+                estimator.insert(&n);
+                estimator.insert(&(n / 2));
+            }
+            assert!(within(estimator.estimate(), actual, 3), "{}", actual);
+        }
+
+        let mut coarse = EasyCardinality::with_precision(4);
+        coarse.extend(0..1000);
+        assert_eq!(coarse.precision(), 4);
+        assert!(within(coarse.estimate(), 1000, 50));
+    }
+
+    #[test]
+    fn merge() {
+        let a = (0..5000).collect::<EasySet<_>>();
+        let b = (2500..7500).collect::<EasySet<_>>();
+        let mut merged = EasyCardinality::from(&a);
+        merged |= &EasyCardinality::from(&b);
+        assert!(within(merged.estimate(), 7500, 3));
+        assert_eq!(merged.estimate(), a.estimate_union_size(&b));
+        assert_eq!(&merged | &merged, merged);
+    }
+
+    #[test]
+    #[should_panic(expected = "estimators with different precisions can't be merged")]
+    fn merge_different_precisions() {
+        let _ = EasyCardinality::<u8>::with_precision(4) | &EasyCardinality::with_precision(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "the precision must be between 4 and 16, but it was 17")]
+    fn invalid_precision() {
+        EasyCardinality::<u8>::with_precision(17);
+    }
+}
@@ -0,0 +1,177 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, Index, IndexMut};
+
+use crate::{EasyMap, EasySet};
+
+/// A string which ignores case when it's compared or hashed, for use as the key of an `EasyMap` or `EasySet`. It keeps
+/// the original string, which is what's displayed.
+///
+/// ```rust
+/// use easy_collections::CaseInsensitive;
+///
+/// assert_eq!(CaseInsensitive::from("Hello"), CaseInsensitive::from("hELLO"));
+/// assert_eq!(CaseInsensitive::from("Hello").to_string(), "Hello");
+/// ```
+///
+/// Case is folded one character at a time with `char::to_lowercase`, which isn't full Unicode case folding: special
+/// cases like the final sigma ("ς" and "σ") or "ß" and "ss" aren't equal. Strings also aren't normalised, so a
+/// precomposed "é" isn't equal to "e" followed by a combining accent. Normalise the strings before wrapping them if
+/// that matters.
+/// ```rust
+/// use easy_collections::CaseInsensitive;
+///
+/// assert_ne!(CaseInsensitive::from("ς"), CaseInsensitive::from("σ"));
+/// assert_ne!(CaseInsensitive::from("\u{e9}"), CaseInsensitive::from("e\u{301}"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CaseInsensitive(pub String);
+
+impl CaseInsensitive {
+    /// Returns the characters of the string, with their case folded.
+    fn folded(&self) -> impl Iterator<Item = char> + '_ {
+        self.0.chars().flat_map(char::to_lowercase)
+    }
+}
+
+impl PartialEq for CaseInsensitive {
+    fn eq(&self, other: &Self) -> bool {
+        self.folded().eq(other.folded())
+    }
+}
+
+impl Eq for CaseInsensitive {}
+
+impl PartialOrd for CaseInsensitive {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CaseInsensitive {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.folded().cmp(other.folded())
+    }
+}
+
+impl Hash for CaseInsensitive {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for c in self.folded() {
+            c.hash(state);
+        }
+    }
+}
+
+impl Display for CaseInsensitive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Deref for CaseInsensitive {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<&str> for CaseInsensitive {
+    fn from(s: &str) -> Self {
+        CaseInsensitive(s.to_string())
+    }
+}
+
+impl From<String> for CaseInsensitive {
+    fn from(s: String) -> Self {
+        CaseInsensitive(s)
+    }
+}
+
+impl<V: Default> EasyMap<CaseInsensitive, V> {
+    /// Create a new `EasyMap` whose keys ignore case. It can be indexed by `&str`s directly, and the first spelling of
+    /// each key which is inserted is kept.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let mut headers = EasyMap::case_insensitive();
+    /// headers["Content-Type"] = "text/html";
+    /// assert_eq!(headers["content-type"], "text/html");
+    /// headers["CONTENT-TYPE"] = "text/plain";
+    /// assert_eq!(headers.len(), 1);
+    /// assert_eq!(headers.keys().next().unwrap().to_string(), "Content-Type");
+    /// ```
+    pub fn case_insensitive() -> EasyMap<CaseInsensitive, V> {
+        EasyMap::new()
+    }
+}
+
+impl<V> Index<&str> for EasyMap<CaseInsensitive, V> {
+    type Output = V;
+    fn index(&self, key: &str) -> &Self::Output {
+        self.get_or_default(&key.into())
+    }
+}
+
+impl<V> IndexMut<&str> for EasyMap<CaseInsensitive, V> {
+    fn index_mut(&mut self, key: &str) -> &mut Self::Output {
+        &mut self[&CaseInsensitive::from(key)]
+    }
+}
+
+impl EasySet<CaseInsensitive> {
+    /// Create a new `EasySet` whose items ignore case. The first spelling of each item which is inserted is kept.
+    ///
+    /// ```rust
+    /// use easy_collections::EasySet;
+    ///
+    /// let mut tags = EasySet::case_insensitive();
+    /// tags.insert("Rust".into());
+    /// assert!(!tags.insert("RUST".into()));
+    /// assert!(tags.contains(&"rust".into()));
+    /// ```
+    pub fn case_insensitive() -> EasySet<CaseInsensitive> {
+        EasySet::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn comparisons() {
+        let a = CaseInsensitive::from("Straße");
+        assert_eq!(a, CaseInsensitive::from("STRAßE"));
+        assert_ne!(a, CaseInsensitive::from("Strasse"));
+        assert_eq!(CaseInsensitive::from("ΣΑΣ"), CaseInsensitive::from("σασ"));
+        assert_eq!(&*a, "Straße");
+        assert!(CaseInsensitive::from("apple") < CaseInsensitive::from("Banana"));
+        assert_eq!(CaseInsensitive::from(String::from("x")).0, "x");
+    }
+
+    #[test]
+    fn map() {
+        let mut map = EasyMap::<_, usize>::case_insensitive();
+        map["Apple"] += 1;
+        map["APPLE"] += 1;
+        map["apple"] += 1;
+        map["Pear"] += 1;
+        assert_eq!(map["aPpLe"], 3);
+        assert_eq!(map["banana"], 0);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[CaseInsensitive::from("PEAR")], 1);
+    }
+
+    #[test]
+    fn set() {
+        let set = vec!["a", "A", "b"]
+            .into_iter()
+            .map(CaseInsensitive::from)
+            .collect::<EasySet<_>>();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&"B".into()));
+        assert!(EasySet::case_insensitive().is_empty());
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::EasyMap;
+
+const DEFAULT_SHARD_COUNT: usize = 16;
+
+/// A map which can be shared between threads, which creates default values for empty keys, the same as `EasyMap`.
+///
+/// Its entries are split between several shards which are locked separately, so threads using different keys rarely
+/// wait for each other. Values are accessed with closures, which run while their shard is locked.
+///
+/// ```rust
+/// use easy_collections::EasyConcurrentMap;
+/// use std::thread;
+///
+/// let counts = EasyConcurrentMap::<_, usize>::new();
+/// thread::scope(|s| {
+///     for word in ["a", "b", "a"] {
+///         let counts = &counts;
+///         s.spawn(move || counts.update(word, |n| *n += 1));
+///     }
+/// });
+/// assert_eq!(counts.get_with(&"a", |n| *n), 2);
+/// assert_eq!(counts.get_with(&"z", |n| *n), 0);
+/// assert_eq!(counts.snapshot()["b"], 1);
+/// ```
+pub struct EasyConcurrentMap<K: Eq + Hash, V> {
+    shards: Vec<Mutex<HashMap<K, V>>>,
+    hasher: RandomState,
+    default: V,
+}
+
+impl<K: Eq + Hash, V: Default> EasyConcurrentMap<K, V> {
+    /// Create a new `EasyConcurrentMap`. The value `V` must implement `Default`.
+    pub fn new() -> EasyConcurrentMap<K, V> {
+        EasyConcurrentMap::new_with_default(V::default())
+    }
+}
+
+impl<K: Eq + Hash, V> EasyConcurrentMap<K, V> {
+    /// Create a new `EasyConcurrentMap`. The value `V` does not need to implement `Default`, instead you provide it
+    /// with one here.
+    pub fn new_with_default(default: V) -> EasyConcurrentMap<K, V> {
+        EasyConcurrentMap::new_with_shards(default, DEFAULT_SHARD_COUNT)
+    }
+
+    /// Create a new `EasyConcurrentMap` with `shards` separately locked shards (at least one is always used).
+    pub fn new_with_shards(default: V, shards: usize) -> EasyConcurrentMap<K, V> {
+        EasyConcurrentMap {
+            shards: (0..shards.max(1)).map(|_| Mutex::default()).collect(),
+            hasher: RandomState::new(),
+            default,
+        }
+    }
+
+    /// Returns the default value, which is used for keys which aren't in the map.
+    pub fn default_value(&self) -> &V {
+        &self.default
+    }
+
+    /// Locks the shard which `k` belongs to. A panic while a shard was locked doesn't leave it in an invalid state, so
+    /// poisoned locks are ignored.
+    fn shard(&self, k: &K) -> MutexGuard<'_, HashMap<K, V>> {
+        let i = self.hasher.hash_one(k) as usize % self.shards.len();
+        self.shards[i]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Same as `HashMap::insert`.
+    pub fn insert(&self, k: K, v: V) -> Option<V> {
+        self.shard(&k).insert(k, v)
+    }
+
+    /// Same as `HashMap::remove`.
+    pub fn remove(&self, k: &K) -> Option<V> {
+        self.shard(k).remove(k)
+    }
+
+    /// Same as `HashMap::contains_key`.
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.shard(k).contains_key(k)
+    }
+
+    /// Calls `f` with the value for `k`, or the default value if it's not in the map, and returns its result.
+    pub fn get_with<R>(&self, k: &K, f: impl FnOnce(&V) -> R) -> R {
+        f(self.shard(k).get(k).unwrap_or(&self.default))
+    }
+
+    /// Calls `f` with the value for `k`, inserting the default value first if it's not in the map, and returns its
+    /// result.
+    pub fn update<R>(&self, k: K, f: impl FnOnce(&mut V) -> R) -> R
+    where
+        V: Clone,
+    {
+        let mut shard = self.shard(&k);
+        f(shard.entry(k).or_insert_with(|| self.default.clone()))
+    }
+
+    /// Returns the number of entries in the map. Other threads may change it at any time.
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|s| s.lock().unwrap_or_else(PoisonError::into_inner).len())
+            .sum()
+    }
+
+    /// Returns whether the map has no entries. Other threads may change it at any time.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> EasyConcurrentMap<K, V> {
+    /// Returns a copy of the entries as an `EasyMap`, with the same default value. Each shard is copied while it's
+    /// locked, but the shards are locked one at a time, so changes from other threads may be partially included.
+    pub fn snapshot(&self) -> EasyMap<K, V> {
+        let mut map = EasyMap::new_with_default(self.default.clone());
+        for shard in &self.shards {
+            let shard = shard.lock().unwrap_or_else(PoisonError::into_inner);
+            map.extend(shard.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+
+        map
+    }
+}
+
+impl<K: Eq + Hash, V: Default> Default for EasyConcurrentMap<K, V> {
+    fn default() -> Self {
+        EasyConcurrentMap::new()
+    }
+}
+
+impl<K: Eq + Hash, V> From<EasyMap<K, V>> for EasyConcurrentMap<K, V> {
+    fn from(map: EasyMap<K, V>) -> Self {
+        let concurrent = EasyConcurrentMap::new_with_default(map.default);
+        for (k, v) in map.inner {
+            concurrent.insert(k, v);
+        }
+
+        concurrent
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::map;
+    use std::thread;
+
+    #[test]
+    fn threads() {
+        let map = EasyConcurrentMap::new_with_shards(vec![], 4);
+        thread::scope(|s| {
+            for t in 0..8 {
+                let map = &map;
+                s.spawn(move || {
+                    for i in 0..100 {
+                        map.update(i % 10, |v: &mut Vec<_>| v.push(t));
+                    }
+                });
+            }
+        });
+        assert_eq!(map.len(), 10);
+        for i in 0..10 {
+            assert_eq!(map.get_with(&i, |v| v.len()), 80);
+        }
+        assert!(map.get_with(&10, |v| v.is_empty()));
+        assert!(!map.contains_key(&10));
+    }
+
+    #[test]
+    fn insert_remove() {
+        let map = EasyConcurrentMap::new_with_shards('-', 0);
+        assert!(map.is_empty());
+        assert_eq!(map.insert(1, 'a'), None);
+        assert_eq!(map.insert(1, 'b'), Some('a'));
+        assert_eq!(map.remove(&1), Some('b'));
+        assert_eq!(map.remove(&1), None);
+        assert_eq!(*map.default_value(), '-');
+    }
+
+    #[test]
+    fn snapshot() {
+        let map = EasyConcurrentMap::from(map! {0; "a" => 1, "b" => 2});
+        map.update("c", |v| *v = 3);
+        let snapshot = map.snapshot();
+        map.insert("d", 4);
+        assert_eq!(snapshot, map! {0; "a" => 1, "b" => 2, "c" => 3});
+        assert_eq!(snapshot["d"], 0);
+    }
+
+    #[test]
+    fn poisoned() {
+        let map = EasyConcurrentMap::<u8, u8>::new_with_shards(0, 1);
+        let _ = thread::scope(|s| s.spawn(|| map.update(1, |_| panic!("oops"))).join());
+        map.insert(2, 2);
+        assert_eq!(map.snapshot(), map! {1 => 0, 2 => 2});
+    }
+
+    #[test]
+    fn values_without_clone() {
+        struct Handle(u8);
+
+        let map = EasyConcurrentMap::new_with_default(Handle(0));
+        map.insert('a', Handle(1));
+        assert_eq!(map.get_with(&'a', |h| h.0), 1);
+        assert_eq!(map.get_with(&'b', |h| h.0), 0);
+    }
+}
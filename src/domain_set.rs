@@ -0,0 +1,241 @@
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display},
+    hash::Hash,
+    ops::{Deref, Not},
+};
+
+use crate::EasySet;
+
+/// A set which knows the universe (or domain) of items it's drawn from, so that `!set` gives its complement: every
+/// item in the universe which isn't in the set. Create one with `EasyDomainSet::new`, `EasyDomainSet::full` or
+/// `EasySet::with_universe`.
+///
+/// ```rust
+/// use easy_collections::set;
+///
+/// let digits = set!{1, 2, 3, 4, 5, 6, 7, 8, 9};
+/// let taken = set!{1, 5, 9}.with_universe(digits);
+/// assert_eq!(*!&taken, set!{2, 3, 4, 6, 7, 8});
+/// assert_eq!(!!taken, set!{1, 5, 9});
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EasyDomainSet<K: Eq + Hash> {
+    pub(crate) inner: EasySet<K>,
+    pub(crate) universe: EasySet<K>,
+}
+
+impl<K: Eq + Hash> EasySet<K> {
+    /// Pairs the set with the universe it's drawn from, which lets you take its complement with `!`. Items which
+    /// aren't in the universe are dropped.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let set = set!{'a', 'z'}.with_universe(set!{'a', 'b', 'c'});
+    /// assert_eq!(*set, set!{'a'});
+    /// assert_eq!(*!set, set!{'b', 'c'});
+    /// ```
+    pub fn with_universe(self, universe: impl Into<EasySet<K>>) -> EasyDomainSet<K> {
+        let universe = universe.into();
+        let mut inner = self;
+        inner.retain(|k| universe.contains(k));
+        EasyDomainSet { inner, universe }
+    }
+}
+
+impl<K: Eq + Hash> EasyDomainSet<K> {
+    /// Create a new, empty `EasyDomainSet` drawn from `universe`.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasyDomainSet};
+    ///
+    /// let mut cell = EasyDomainSet::new(1..=9);
+    /// assert_eq!(cell.insert(5), Ok(true));
+    /// assert!(cell.insert(10).is_err());
+    /// assert_eq!(cell, set!{5});
+    /// ```
+    pub fn new(universe: impl IntoIterator<Item = K>) -> EasyDomainSet<K> {
+        EasyDomainSet {
+            inner: EasySet::new(),
+            universe: universe.into_iter().collect(),
+        }
+    }
+
+    /// Inserts `k` into the set, returning whether it was newly inserted. If `k` isn't in the universe, the set is
+    /// unchanged and `k` is returned in the error.
+    pub fn insert(&mut self, k: K) -> Result<bool, NotInDomain<K>> {
+        if self.universe.contains(&k) {
+            Ok(self.inner.insert(k))
+        } else {
+            Err(NotInDomain { item: k })
+        }
+    }
+
+    /// Same as `HashSet::remove`.
+    pub fn remove(&mut self, k: &K) -> bool {
+        self.inner.remove(k)
+    }
+
+    /// Returns whether every item in the universe is in the set.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyDomainSet;
+    ///
+    /// let mut set = EasyDomainSet::new(vec!['x', 'o']);
+    /// set.insert('x').unwrap();
+    /// assert!(!set.is_full());
+    /// set.insert('o').unwrap();
+    /// assert!(set.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.inner.len() == self.universe.len()
+    }
+
+    /// Returns the universe the set is drawn from.
+    pub fn universe(&self) -> &EasySet<K> {
+        &self.universe
+    }
+
+    /// Returns the items in the set, without the universe.
+    pub fn into_set(self) -> EasySet<K> {
+        self.inner
+    }
+}
+
+impl<K: Eq + Hash + Clone> EasyDomainSet<K> {
+    /// Create a new `EasyDomainSet` which contains every item in `universe`.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyDomainSet;
+    ///
+    /// let mut candidates = EasyDomainSet::full(1..=4);
+    /// candidates.remove(&2);
+    /// assert_eq!(candidates.len(), 3);
+    /// ```
+    pub fn full(universe: impl IntoIterator<Item = K>) -> EasyDomainSet<K> {
+        let universe = universe.into_iter().collect::<EasySet<K>>();
+        EasyDomainSet {
+            inner: universe.clone(),
+            universe,
+        }
+    }
+
+    /// Returns the items in the universe which aren't in the set. This is the same as `!set`, without the universe.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasyDomainSet};
+    ///
+    /// let mut row = EasyDomainSet::new(1..=4);
+    /// row.insert(1).unwrap();
+    /// row.insert(3).unwrap();
+    /// assert_eq!(row.missing(), set!{2, 4});
+    /// ```
+    pub fn missing(&self) -> EasySet<K> {
+        &self.universe - &self.inner
+    }
+}
+
+impl<K: Eq + Hash> Deref for EasyDomainSet<K> {
+    type Target = EasySet<K>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K: Eq + Hash> PartialEq<EasySet<K>> for EasyDomainSet<K> {
+    fn eq(&self, other: &EasySet<K>) -> bool {
+        self.inner == *other
+    }
+}
+
+impl<K: Eq + Hash + Clone> Not for &EasyDomainSet<K> {
+    type Output = EasyDomainSet<K>;
+    fn not(self) -> Self::Output {
+        EasyDomainSet {
+            inner: self.missing(),
+            universe: self.universe.clone(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> Not for EasyDomainSet<K> {
+    type Output = EasyDomainSet<K>;
+    fn not(self) -> Self::Output {
+        !&self
+    }
+}
+
+/// The error returned by `EasyDomainSet::insert` when the item isn't in the universe of the set.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NotInDomain<K> {
+    /// The item which couldn't be inserted.
+    pub item: K,
+}
+
+impl<K: Debug> Display for NotInDomain<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the item is not in the domain of the set: {:?}",
+            self.item
+        )
+    }
+}
+
+impl<K: Debug> Error for NotInDomain<K> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::set;
+
+    #[test]
+    fn complement() {
+        let universe = set! {1, 2, 3, 4};
+        let set = set! {}.with_universe(universe.clone());
+        assert_eq!(!&set, universe);
+        assert_eq!(*set.universe(), universe);
+
+        let set = set! {2, 3, 7}.with_universe(universe.clone());
+        assert_eq!(set, set! {2, 3});
+        let complement = !&set;
+        assert_eq!(complement, set! {1, 4});
+        assert_eq!(complement.universe(), set.universe());
+        assert_eq!(!complement, set);
+        assert_eq!(!universe.clone().with_universe(universe.clone()), set! {});
+        assert!(set.into_set().contains(&2));
+    }
+
+    #[test]
+    fn insert() {
+        let mut set = EasyDomainSet::new(vec!["a", "b"]);
+        assert_eq!(set.insert("a"), Ok(true));
+        assert_eq!(set.insert("a"), Ok(false));
+        let err = set.insert("c").unwrap_err();
+        assert_eq!(err, NotInDomain { item: "c" });
+        assert_eq!(
+            err.to_string(),
+            "the item is not in the domain of the set: \"c\""
+        );
+        assert_eq!(set, set! {"a"});
+        assert!(set.remove(&"a"));
+        assert!(!set.remove(&"c"));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn full_and_missing() {
+        let mut set = EasyDomainSet::full('a'..='c');
+        assert!(set.is_full());
+        assert!(set.missing().is_empty());
+        set.remove(&'b');
+        assert!(!set.is_full());
+        assert_eq!(set.missing(), set! {'b'});
+        assert_eq!(!set, set! {'b'});
+
+        let empty = EasyDomainSet::<u8>::new(vec![]);
+        assert!(empty.is_full());
+        assert_eq!(!empty, set! {});
+    }
+}
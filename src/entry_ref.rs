@@ -0,0 +1,97 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::EasyMap;
+
+/// An entry of an `EasyMap` found by a borrowed key, returned by `EasyMap::entry_ref`. The key is only converted into
+/// an owned key if the entry is inserted.
+pub struct EasyEntryRef<'a, 'q, K: Eq + Hash, V, Q: ?Sized> {
+    map: &'a mut EasyMap<K, V>,
+    key: &'q Q,
+}
+
+impl<K: Eq + Hash, V> EasyMap<K, V> {
+    /// Returns the entry for a borrowed key, like `EasyMap::entry`, but without needing an owned key unless the entry
+    /// is inserted. For maps with `String` keys, this avoids allocating a `String` for every lookup.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let mut counts: EasyMap<String, usize> = EasyMap::new();
+    /// for word in "the cat and the hat".split(' ') {
+    ///     *counts.entry_ref(word).or_default() += 1;
+    /// }
+    /// assert_eq!(counts["the".to_string()], 2);
+    /// assert_eq!(counts.len(), 4);
+    /// ```
+    pub fn entry_ref<'a, 'q, Q>(&'a mut self, key: &'q Q) -> EasyEntryRef<'a, 'q, K, V, Q>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash + ToOwned<Owned = K>,
+    {
+        EasyEntryRef { map: self, key }
+    }
+}
+
+impl<'a, K, V, Q> EasyEntryRef<'a, '_, K, V, Q>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: ?Sized + Eq + Hash + ToOwned<Owned = K>,
+{
+    /// Returns the borrowed key of the entry.
+    pub fn key(&self) -> &Q {
+        self.key
+    }
+
+    /// Returns the value of the entry, inserting the value from `f` if it's not in the map.
+    pub fn or_insert_with(self, f: impl FnOnce() -> V) -> &'a mut V {
+        if !self.map.inner.contains_key(self.key) {
+            self.map.inner.insert(self.key.to_owned(), f());
+        }
+
+        self.map.inner.get_mut(self.key).unwrap()
+    }
+
+    /// Returns the value of the entry, inserting `v` if it's not in the map.
+    pub fn or_insert(self, v: V) -> &'a mut V {
+        self.or_insert_with(|| v)
+    }
+
+    /// Returns the value of the entry, inserting the map's default value if it's not in the map.
+    pub fn or_default(self) -> &'a mut V {
+        let default = self.map.fill_default();
+        self.or_insert(default)
+    }
+
+    /// Calls `f` with the value of the entry, if it's in the map.
+    pub fn and_modify(self, f: impl FnOnce(&mut V)) -> Self {
+        if let Some(v) = self.map.inner.get_mut(self.key) {
+            f(v);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{map, EasyMap};
+
+    #[test]
+    fn entry_ref() {
+        let mut map: EasyMap<String, Vec<u8>> = map! {vec![0]};
+        map.entry_ref("a").or_default().push(1);
+        map.entry_ref("a").or_insert(vec![9]).push(2);
+        map.entry_ref("b").or_insert_with(|| vec![3]);
+        map.entry_ref("c").and_modify(|v| v.push(4));
+        let entry = map.entry_ref("b").and_modify(|v| v.push(5));
+        assert_eq!(entry.key(), "b");
+        assert_eq!(map["a".to_string()], vec![0, 1, 2]);
+        assert_eq!(map["b".to_string()], vec![3, 5]);
+        assert_eq!(map.len(), 2);
+
+        let mut paths: EasyMap<std::path::PathBuf, u8> = EasyMap::new();
+        *paths.entry_ref(std::path::Path::new("/tmp")).or_default() += 1;
+        assert_eq!(paths.len(), 1);
+    }
+}
@@ -0,0 +1,168 @@
+use std::hash::Hash;
+
+use crate::{EasyMap, EasySet};
+
+/// A part of a glob pattern.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Token {
+    Char(char),
+    /// `?`, which matches one character other than `/`.
+    One,
+    /// `*`, which matches any characters other than `/`.
+    Star,
+    /// `**`, which matches any characters.
+    Globstar,
+}
+
+fn tokens(pattern: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '?' => Token::One,
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                Token::Globstar
+            }
+            '*' => Token::Star,
+            c => Token::Char(c),
+        });
+    }
+
+    tokens
+}
+
+/// Returns whether `text` matches the glob `pattern`. Matching is done with a table of which prefixes of the pattern
+/// match which prefixes of the text, so patterns with many stars don't backtrack.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let tokens = tokens(pattern);
+    let text = text.chars().collect::<Vec<_>>();
+
+    // `row[j]` is whether the tokens so far match the first `j` characters of the text
+    let mut row = vec![false; text.len() + 1];
+    row[0] = true;
+    for token in tokens {
+        let mut next = vec![false; text.len() + 1];
+        for j in 0..=text.len() {
+            next[j] = match token {
+                Token::Star => row[j] || (j > 0 && text[j - 1] != '/' && next[j - 1]),
+                Token::Globstar => row[j] || (j > 0 && next[j - 1]),
+                Token::One => j > 0 && text[j - 1] != '/' && row[j - 1],
+                Token::Char(c) => j > 0 && text[j - 1] == c && row[j - 1],
+            };
+        }
+        row = next;
+    }
+
+    row[text.len()]
+}
+
+impl<K: Eq + Hash + AsRef<str>, V> EasyMap<K, V> {
+    /// Returns an iterator over the entries whose keys start with `prefix`, in an arbitrary order.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let map = map!{"src/lib.rs" => 1, "src/map.rs" => 2, "tests/map.rs" => 3};
+    /// assert_eq!(map.entries_with_prefix("src/").count(), 2);
+    /// ```
+    pub fn entries_with_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = (&'a K, &'a V)> + 'a {
+        self.iter()
+            .filter(move |(k, _)| k.as_ref().starts_with(prefix))
+    }
+
+    /// Returns an iterator over the entries whose keys match the glob `pattern`, in an arbitrary order. In the pattern,
+    /// `?` matches one character and `*` matches any number of characters, but neither matches `/`; `**` matches any
+    /// number of characters, including `/`.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let map = map!{"foo/a/bar" => 1, "foo/a/b/bar" => 2, "foo/bar" => 3};
+    /// assert_eq!(map.entries_glob("foo/*/bar").collect::<Vec<_>>(), vec![(&"foo/a/bar", &1)]);
+    /// assert_eq!(map.entries_glob("foo/**").count(), 3);
+    /// ```
+    pub fn entries_glob<'a>(
+        &'a self,
+        pattern: &'a str,
+    ) -> impl Iterator<Item = (&'a K, &'a V)> + 'a {
+        self.iter()
+            .filter(move |(k, _)| glob_match(pattern, k.as_ref()))
+    }
+}
+
+impl<K: Eq + Hash + AsRef<str>> EasySet<K> {
+    /// Returns an iterator over the items which start with `prefix`, in an arbitrary order.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let set = set!{"user/1", "user/2", "group/1"};
+    /// assert_eq!(set.items_with_prefix("user/").count(), 2);
+    /// ```
+    pub fn items_with_prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a K> + 'a {
+        self.iter().filter(move |k| k.as_ref().starts_with(prefix))
+    }
+
+    /// Returns an iterator over the items which match the glob `pattern`, in an arbitrary order. See
+    /// `EasyMap::entries_glob` for the syntax of the pattern.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let set = set!{"a.rs", "b.rs", "c.toml", "d/e.rs"};
+    /// assert_eq!(set.items_glob("*.rs").count(), 2);
+    /// ```
+    pub fn items_glob<'a>(&'a self, pattern: &'a str) -> impl Iterator<Item = &'a K> + 'a {
+        self.iter().filter(move |k| glob_match(pattern, k.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{map, set};
+
+    #[test]
+    fn matching() {
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "a"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("a?c", "abc"));
+        assert!(!glob_match("a?c", "a/c"));
+        assert!(glob_match("*.rs", "lib.rs"));
+        assert!(!glob_match("*.rs", "src/lib.rs"));
+        assert!(glob_match("**.rs", "src/lib.rs"));
+        assert!(glob_match("src/**/mod.rs", "src/a/b/mod.rs"));
+        assert!(glob_match("*a*a*a*a*", "aaaa"));
+        assert!(!glob_match("*a*a*a*a*b", &"a".repeat(100)));
+        assert!(glob_match("ünï*", "ünïcode"));
+    }
+
+    #[test]
+    fn queries() {
+        let map: EasyMap<String, u8> = map! {
+            "a/x".to_string() => 1,
+            "a/y/z".to_string() => 2,
+            "b/x".to_string() => 3,
+        };
+        let mut prefixed = map
+            .entries_with_prefix("a/")
+            .map(|(_, v)| *v)
+            .collect::<Vec<_>>();
+        prefixed.sort();
+        assert_eq!(prefixed, vec![1, 2]);
+        assert_eq!(map.entries_with_prefix("").count(), 3);
+        let mut globbed = map.entries_glob("*/x").map(|(_, v)| *v).collect::<Vec<_>>();
+        globbed.sort();
+        assert_eq!(globbed, vec![1, 3]);
+
+        let set = set! {"a/x", "a/y/z", "b/x"};
+        assert_eq!(set.items_with_prefix("b").collect::<Vec<_>>(), vec![&"b/x"]);
+        assert_eq!(set.items_glob("a/**").count(), 2);
+        assert_eq!(set.items_glob("?").count(), 0);
+    }
+}
@@ -0,0 +1,630 @@
+use crate::{EasyMap, EasySet};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::ops::Add;
+
+/// A directed graph, stored as an `EasyMap` from each node to the `EasySet` of nodes its edges point to.
+///
+/// ```rust
+/// use easy_collections::{set, EasyGraph};
+///
+/// let mut graph = EasyGraph::new();
+/// graph.add_edge("a", "b");
+/// graph.add_edge("a", "c");
+/// graph.add_node("d");
+/// assert_eq!(*graph.neighbours(&"a"), set!{"b", "c"});
+/// assert!(graph.neighbours(&"d").is_empty());
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.edge_count(), 2);
+/// ```
+///
+/// Edges can also have weights, which are stored in an `EasyMap` keyed by `(from, to)`. Edges without a weight use
+/// the default weight, which is `1` unless it's changed:
+/// ```rust
+/// use easy_collections::EasyGraph;
+///
+/// let mut graph = EasyGraph::new();
+/// graph.add_weighted_edge("a", "b", 5);
+/// graph.add_edge("b", "c");
+/// assert_eq!(*graph.weight(&"a", &"b"), 5);
+/// assert_eq!(*graph.weight(&"b", &"c"), 1);
+/// assert_eq!(graph.weighted_distances("a")["c"], 6);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EasyGraph<N: Eq + Hash + Clone, W: Clone = usize> {
+    pub(crate) edges: EasyMap<N, EasySet<N>>,
+    pub(crate) weights: EasyMap<(N, N), W>,
+}
+
+impl<N: Eq + Hash + Clone> EasyGraph<N> {
+    /// Create a new, empty `EasyGraph`, where edges have a default weight of `1`.
+    pub fn new() -> EasyGraph<N> {
+        EasyGraph::new_with_default_weight(1)
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Clone> EasyGraph<N, W> {
+    /// Create a new, empty `EasyGraph`, with the weight to use for edges which are added without one.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyGraph;
+    ///
+    /// let mut graph = EasyGraph::new_with_default_weight(0.5);
+    /// graph.add_edge(1, 2);
+    /// assert_eq!(*graph.weight(&1, &2), 0.5);
+    /// ```
+    pub fn new_with_default_weight(weight: W) -> EasyGraph<N, W> {
+        EasyGraph {
+            edges: EasyMap::new(),
+            weights: EasyMap::new_with_default(weight),
+        }
+    }
+
+    /// Returns the weight used for edges which were added without one.
+    pub fn default_weight(&self) -> &W {
+        self.weights.default_value()
+    }
+
+    /// Returns the weight of the edge from `a` to `b`, or the default weight if it doesn't have one.
+    pub fn weight(&self, a: &N, b: &N) -> &W {
+        self.weights.get_or_default(&(a.clone(), b.clone()))
+    }
+
+    /// Returns the weights of the edges which have one.
+    pub fn weights(&self) -> &EasyMap<(N, N), W> {
+        &self.weights
+    }
+
+    /// Adds an edge from `a` to `b` with a weight, replacing its previous weight if it already existed.
+    pub fn add_weighted_edge(&mut self, a: N, b: N, weight: W) {
+        self.weights.insert((a.clone(), b.clone()), weight);
+        self.add_edge(a, b);
+    }
+
+    /// Adds a node to the graph, returning whether it was newly added.
+    pub fn add_node(&mut self, n: N) -> bool {
+        if self.edges.contains_key(&n) {
+            return false;
+        }
+
+        self.edges.insert(n, EasySet::new());
+        true
+    }
+
+    /// Adds an edge from `a` to `b`, adding the nodes too if they're not in the graph yet. Returns whether the edge
+    /// was newly added.
+    pub fn add_edge(&mut self, a: N, b: N) -> bool {
+        self.add_node(b.clone());
+        self.edges.insert_into(a, b)
+    }
+
+    /// Adds edges in both directions between `a` and `b`.
+    pub fn add_undirected_edge(&mut self, a: N, b: N) {
+        self.add_edge(a.clone(), b.clone());
+        self.add_edge(b, a);
+    }
+
+    /// Returns whether the node is in the graph.
+    pub fn contains_node(&self, n: &N) -> bool {
+        self.edges.contains_key(n)
+    }
+
+    /// Returns whether there's an edge from `a` to `b`.
+    pub fn contains_edge(&self, a: &N, b: &N) -> bool {
+        self.neighbours(a).contains(b)
+    }
+
+    /// Returns the nodes which the edges from `n` point to. This is empty if `n` isn't in the graph.
+    pub fn neighbours(&self, n: &N) -> &EasySet<N> {
+        self.edges.get_or_default(n)
+    }
+
+    /// Returns an iterator over the nodes of the graph, in an arbitrary order.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.edges.keys()
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.values().map(|set| set.len()).sum()
+    }
+
+    /// Returns the number of edges on the shortest path from `start` to every node it can reach, using a breadth-first
+    /// search. Nodes which can't be reached aren't in the map.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyGraph;
+    ///
+    /// let graph = vec![(1, 2), (2, 3), (1, 3), (3, 4), (5, 1)].into_iter().collect::<EasyGraph<_>>();
+    /// let dist = graph.bfs_distances(1);
+    /// assert_eq!(dist[1], 0);
+    /// assert_eq!(dist[3], 1);
+    /// assert_eq!(dist[4], 2);
+    /// assert!(!dist.contains_key(&5));
+    /// ```
+    pub fn bfs_distances(&self, start: N) -> EasyMap<N, usize> {
+        let mut dist = EasyMap::new();
+        let mut queue = VecDeque::new();
+        dist.insert(start.clone(), 0);
+        queue.push_back((start, 0));
+        while let Some((n, d)) = queue.pop_front() {
+            for next in self.neighbours(&n).iter() {
+                if !dist.contains_key(next) {
+                    dist.insert(next.clone(), d + 1);
+                    queue.push_back((next.clone(), d + 1));
+                }
+            }
+        }
+
+        dist
+    }
+
+    /// Returns the cost of the cheapest path from `start` to every node it can reach, using Dijkstra's algorithm, where
+    /// `weight` returns the cost of the edge between two nodes. Nodes which can't be reached aren't in the map.
+    ///
+    /// The costs start from `C::default()`, and must not be negative.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyGraph;
+    ///
+    /// let graph = vec![('a', 'b'), ('b', 'c'), ('a', 'c')].into_iter().collect::<EasyGraph<_>>();
+    /// let dist = graph.dijkstra('a', |a, b| if (*a, *b) == ('a', 'c') { 10 } else { 1 });
+    /// assert_eq!(dist['b'], 1);
+    /// assert_eq!(dist['c'], 2);
+    /// ```
+    pub fn dijkstra<C, F>(&self, start: N, mut weight: F) -> EasyMap<N, C>
+    where
+        C: Copy + Default + Ord + Add<Output = C>,
+        F: FnMut(&N, &N) -> C,
+    {
+        // the heap refers to nodes by their index, so they don't need to implement `Ord`
+        let mut nodes = vec![start.clone()];
+        let mut dist = EasyMap::new();
+        let mut heap = BinaryHeap::new();
+        dist.insert(start, C::default());
+        heap.push((Reverse(C::default()), 0));
+        while let Some((Reverse(d), i)) = heap.pop() {
+            let n = nodes[i].clone();
+            if dist.get(&n).is_some_and(|best| d > *best) {
+                continue;
+            }
+
+            for next in self.neighbours(&n).iter() {
+                let d = d + weight(&n, next);
+                if dist.get(next).is_none_or(|best| d < *best) {
+                    dist.insert(next.clone(), d);
+                    heap.push((Reverse(d), nodes.len()));
+                    nodes.push(next.clone());
+                }
+            }
+        }
+
+        dist
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Clone> EasyGraph<N, W> {
+    /// Returns the nodes sorted so that every edge points from an earlier node to a later one, for example so tasks
+    /// come after the tasks they depend on. If the graph has a cycle then there's no such order, and one of its cycles
+    /// is returned instead.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyGraph;
+    ///
+    /// // "b" depends on "a", and "c" depends on "b"
+    /// let mut graph = vec![("a", "b"), ("b", "c")].into_iter().collect::<EasyGraph<_>>();
+    /// assert_eq!(graph.topo_sort(), Ok(vec!["a", "b", "c"]));
+    ///
+    /// graph.add_edge("c", "a");
+    /// let cycle = graph.topo_sort().unwrap_err();
+    /// assert_eq!(cycle.nodes.len(), 4);
+    /// assert_eq!(cycle.nodes.first(), cycle.nodes.last());
+    /// ```
+    pub fn topo_sort(&self) -> Result<Vec<N>, Cycle<N>> {
+        let mut in_degree: EasyMap<&N, usize> = self.nodes().map(|n| (n, 0)).collect();
+        for n in self.edges.values().flat_map(|set| set.iter()) {
+            in_degree.incr(n);
+        }
+
+        let mut ready = in_degree
+            .iter()
+            .filter(|(_, d)| **d == 0)
+            .map(|(n, _)| *n)
+            .collect::<Vec<_>>();
+        let mut sorted = Vec::with_capacity(self.node_count());
+        while let Some(n) = ready.pop() {
+            sorted.push(n.clone());
+            for next in self.neighbours(n).iter() {
+                in_degree.decr(next);
+                if in_degree[next] == 0 {
+                    ready.push(next);
+                }
+            }
+        }
+
+        if sorted.len() == self.node_count() {
+            return Ok(sorted);
+        }
+
+        // every node which wasn't sorted has an edge from another unsorted node, so walking backwards along those
+        // edges must eventually revisit a node
+        let mut sources: HashMap<&N, &N> = HashMap::new();
+        for (a, set) in self.edges.iter().filter(|(a, _)| in_degree[*a] > 0) {
+            for b in set.iter().filter(|b| in_degree[*b] > 0) {
+                sources.insert(b, a);
+            }
+        }
+
+        let mut walk = vec![*sources.keys().next().expect("unsorted nodes must exist")];
+        let start = loop {
+            let prev = sources[walk.last().unwrap()];
+            if let Some(i) = walk.iter().position(|n| *n == prev) {
+                break i;
+            }
+            walk.push(prev);
+        };
+        let mut nodes = walk[start..]
+            .iter()
+            .rev()
+            .map(|n| (*n).clone())
+            .collect::<Vec<_>>();
+        nodes.push(nodes[0].clone());
+        Err(Cycle { nodes })
+    }
+
+    /// Returns the strongly connected components of the graph, which are the groups of nodes that can all reach each
+    /// other. Every node is in exactly one component.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasyGraph};
+    ///
+    /// let graph = vec![(1, 2), (2, 1), (2, 3), (3, 4), (4, 3)].into_iter().collect::<EasyGraph<_>>();
+    /// let mut components = graph.strongly_connected_components();
+    /// components.sort_by_key(|c| *c.iter().min().unwrap());
+    /// assert_eq!(components, vec![set!{1, 2}, set!{3, 4}]);
+    /// ```
+    pub fn strongly_connected_components(&self) -> Vec<EasySet<N>> {
+        // this is Kosaraju's algorithm, using indexes for the nodes and explicit stacks rather than recursion
+        let nodes = self.nodes().collect::<Vec<_>>();
+        let index = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (*n, i))
+            .collect::<HashMap<_, _>>();
+        let mut adj = vec![vec![]; nodes.len()];
+        let mut rev = vec![vec![]; nodes.len()];
+        for (a, set) in self.edges.iter() {
+            for b in set.iter() {
+                adj[index[a]].push(index[b]);
+                rev[index[b]].push(index[a]);
+            }
+        }
+
+        // order the nodes by when the depth first search finishes with them
+        let mut visited = vec![false; nodes.len()];
+        let mut finished = Vec::with_capacity(nodes.len());
+        for start in 0..nodes.len() {
+            if visited[start] {
+                continue;
+            }
+
+            visited[start] = true;
+            let mut stack = vec![(start, 0)];
+            while let Some((n, next)) = stack.last_mut() {
+                let n = *n;
+                match adj[n].get(*next) {
+                    Some(&m) => {
+                        *next += 1;
+                        if !visited[m] {
+                            visited[m] = true;
+                            stack.push((m, 0));
+                        }
+                    }
+                    None => {
+                        stack.pop();
+                        finished.push(n);
+                    }
+                }
+            }
+        }
+
+        // then, in reverse finishing order, everything that can reach each node is in its component
+        let mut assigned = vec![false; nodes.len()];
+        let mut components = vec![];
+        for &start in finished.iter().rev() {
+            if assigned[start] {
+                continue;
+            }
+
+            assigned[start] = true;
+            let mut component = EasySet::new();
+            let mut stack = vec![start];
+            while let Some(n) = stack.pop() {
+                component.insert(nodes[n].clone());
+                for &m in &rev[n] {
+                    if !assigned[m] {
+                        assigned[m] = true;
+                        stack.push(m);
+                    }
+                }
+            }
+            components.push(component);
+        }
+
+        components
+    }
+}
+
+/// The error returned by `EasyGraph::topo_sort` when the graph has a cycle.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Cycle<N> {
+    /// The nodes of the cycle, in the order of its edges. The first node is repeated at the end.
+    pub nodes: Vec<N>,
+}
+
+impl<N: Debug> Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the graph has a cycle: {:?}", self.nodes)
+    }
+}
+
+impl<N: Debug> Error for Cycle<N> {}
+
+impl<N: Eq + Hash + Clone> Default for EasyGraph<N> {
+    fn default() -> Self {
+        EasyGraph::new()
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Copy + Default + Ord + Add<Output = W>> EasyGraph<N, W> {
+    /// Returns the cost of the cheapest path from `start` to every node it can reach, using the weights of the edges.
+    /// This is the same as `EasyGraph::dijkstra`, with `EasyGraph::weight` as the cost of each edge.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyGraph;
+    ///
+    /// let graph = vec![('a', 'b', 1), ('b', 'c', 1), ('a', 'c', 5)].into_iter().collect::<EasyGraph<_, _>>();
+    /// let dist = graph.weighted_distances('a');
+    /// assert_eq!(dist['c'], 2);
+    /// ```
+    pub fn weighted_distances(&self, start: N) -> EasyMap<N, W> {
+        self.dijkstra(start, |a, b| *self.weight(a, b))
+    }
+
+    /// Returns the shortest path from `start` to `goal` (including both), using the A* algorithm with `EasyGraph::weight`
+    /// as the cost of each edge, or `None` if there isn't one. The heuristic `h` estimates the cost from a node to the
+    /// goal, and must never overestimate it.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyGraph;
+    ///
+    /// let mut grid = EasyGraph::new();
+    /// for x in 0..5_i32 {
+    ///     for y in 0..5_i32 {
+    ///         grid.add_undirected_edge((x, y), (x + 1, y));
+    ///         grid.add_undirected_edge((x, y), (x, y + 1));
+    ///     }
+    /// }
+    /// let goal = (3, 2);
+    /// let path = grid.a_star((0, 0), goal, |(x, y)| ((goal.0 - x).abs() + (goal.1 - y).abs()) as usize);
+    /// assert_eq!(path.unwrap().len(), 6);
+    /// assert_eq!(grid.a_star((0, 0), (9, 9), |_| 0), None);
+    /// ```
+    pub fn a_star<H>(&self, start: N, goal: N, mut h: H) -> Option<Vec<N>>
+    where
+        H: FnMut(&N) -> W,
+    {
+        let mut nodes = vec![start.clone()];
+        let mut came_from = HashMap::new();
+        let mut cost = HashMap::new();
+        let mut heap = BinaryHeap::new();
+        cost.insert(start.clone(), W::default());
+        heap.push((Reverse(h(&start)), W::default(), 0));
+        while let Some((_, g, i)) = heap.pop() {
+            let n = nodes[i].clone();
+            if n == goal {
+                let mut path = vec![n];
+                while let Some(prev) = came_from.get(path.last().unwrap()) {
+                    path.push(Clone::clone(prev));
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if cost.get(&n).is_some_and(|best| g > *best) {
+                continue;
+            }
+
+            for next in self.neighbours(&n).iter() {
+                let g = g + *self.weight(&n, next);
+                if cost.get(next).is_none_or(|best| g < *best) {
+                    cost.insert(next.clone(), g);
+                    came_from.insert(next.clone(), n.clone());
+                    heap.push((Reverse(g + h(next)), g, nodes.len()));
+                    nodes.push(next.clone());
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Creates a graph from its weighted edges, as `(from, to, weight)`.
+impl<N: Eq + Hash + Clone, W: Clone + From<u8>> FromIterator<(N, N, W)> for EasyGraph<N, W> {
+    fn from_iter<T: IntoIterator<Item = (N, N, W)>>(iter: T) -> Self {
+        let mut graph = EasyGraph::new_with_default_weight(W::from(1));
+        for (a, b, w) in iter {
+            graph.add_weighted_edge(a, b, w);
+        }
+
+        graph
+    }
+}
+
+/// Creates a graph from its edges.
+impl<N: Eq + Hash + Clone> FromIterator<(N, N)> for EasyGraph<N> {
+    fn from_iter<T: IntoIterator<Item = (N, N)>>(iter: T) -> Self {
+        let mut graph = EasyGraph::new();
+        for (a, b) in iter {
+            graph.add_edge(a, b);
+        }
+
+        graph
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::set;
+
+    fn graph(edges: &[(u8, u8)]) -> EasyGraph<u8> {
+        edges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn nodes_and_edges() {
+        let mut g = graph(&[(1, 2), (2, 3)]);
+        assert!(!g.add_edge(1, 2));
+        assert!(g.add_edge(2, 1));
+        assert!(!g.add_node(3));
+        g.add_undirected_edge(4, 5);
+
+        assert_eq!(g.node_count(), 5);
+        assert_eq!(g.edge_count(), 5);
+        assert!(g.contains_edge(&5, &4));
+        assert!(!g.contains_edge(&3, &2));
+        assert!(g.contains_node(&3));
+        assert!(!g.contains_node(&6));
+        assert_eq!(*g.neighbours(&2), set! {1, 3});
+        assert_eq!(
+            g.nodes().cloned().collect::<EasySet<_>>(),
+            set! {1, 2, 3, 4, 5}
+        );
+    }
+
+    #[test]
+    fn bfs_distances() {
+        let g = graph(&[(1, 2), (2, 3), (3, 1), (3, 4)]);
+        let dist = g.bfs_distances(2);
+        assert_eq!(dist, crate::map! {2 => 0, 3 => 1, 1 => 2, 4 => 2});
+        assert_eq!(g.bfs_distances(4), crate::map! {4 => 0});
+        assert_eq!(g.bfs_distances(9), crate::map! {9 => 0});
+    }
+
+    #[test]
+    fn dijkstra() {
+        let g = graph(&[(1, 2), (2, 3), (1, 3), (3, 4), (4, 1)]);
+        let weights = crate::map! {(1, 2) => 1, (2, 3) => 2, (1, 3) => 5, (3, 4) => 1, (4, 1) => 1};
+        let dist = g.dijkstra(1, |a, b| weights[(*a, *b)]);
+        assert_eq!(dist, crate::map! {1 => 0, 2 => 1, 3 => 3, 4 => 4});
+
+        let dist = g.dijkstra(3, |_, _| 1_u64);
+        assert_eq!(dist[1], 2);
+        assert_eq!(dist[2], 3);
+    }
+
+    #[test]
+    fn a_star() {
+        let g = graph(&[(1, 2), (2, 3), (3, 4), (1, 5), (5, 4), (6, 1)]);
+        assert_eq!(g.a_star(1, 4, |_| 0), Some(vec![1, 5, 4]));
+        assert_eq!(g.a_star(1, 1, |_| 0), Some(vec![1]));
+        assert_eq!(g.a_star(4, 1, |_| 0), None);
+        assert_eq!(g.a_star(6, 3, |_| 0), Some(vec![6, 1, 2, 3]));
+
+        // the path with the fewest edges isn't the cheapest one
+        let g: EasyGraph<_, _> = vec![(1, 2, 10), (2, 4, 10), (1, 3, 1), (3, 5, 1), (5, 4, 1)]
+            .into_iter()
+            .collect();
+        assert_eq!(g.a_star(1, 4, |_| 0), Some(vec![1, 3, 5, 4]));
+    }
+
+    #[test]
+    fn topo_sort() {
+        let g = graph(&[(3, 1), (1, 2), (3, 2), (4, 3)]);
+        assert_eq!(g.topo_sort(), Ok(vec![4, 3, 1, 2]));
+
+        let mut g = graph(&[(1, 2), (2, 3), (3, 4), (5, 6)]);
+        let sorted = g.topo_sort().unwrap();
+        assert_eq!(sorted.len(), 6);
+        for (a, b) in [(1, 2), (2, 3), (3, 4), (5, 6)] {
+            let pos = |n| sorted.iter().position(|m| *m == n).unwrap();
+            assert!(pos(a) < pos(b));
+        }
+
+        g.add_edge(4, 2);
+        let cycle = g.topo_sort().unwrap_err();
+        assert_eq!(cycle.nodes.len(), 4);
+        assert_eq!(cycle.nodes.first(), cycle.nodes.last());
+        for pair in cycle.nodes.windows(2) {
+            assert!(g.contains_edge(&pair[0], &pair[1]));
+        }
+        assert!(cycle.to_string().starts_with("the graph has a cycle: ["));
+
+        let g = graph(&[(7, 7)]);
+        assert_eq!(g.topo_sort(), Err(Cycle { nodes: vec![7, 7] }));
+        assert_eq!(EasyGraph::<u8>::new().topo_sort(), Ok(vec![]));
+    }
+
+    #[test]
+    fn strongly_connected_components() {
+        let mut g = graph(&[
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 4),
+            (7, 7),
+        ]);
+        g.add_node(8);
+        let mut components = g.strongly_connected_components();
+        components.sort_by_key(|c| *c.iter().min().unwrap());
+        assert_eq!(
+            components,
+            vec![set! {1, 2, 3}, set! {4, 5, 6}, set! {7}, set! {8}]
+        );
+        assert!(EasyGraph::<u8>::new()
+            .strongly_connected_components()
+            .is_empty());
+    }
+
+    #[test]
+    fn weights() {
+        let mut g = EasyGraph::new_with_default_weight(10_u32);
+        g.add_edge(1, 2);
+        g.add_weighted_edge(2, 3, 1);
+        g.add_weighted_edge(1, 3, 20);
+        assert_eq!(*g.default_weight(), 10);
+        assert_eq!(*g.weight(&1, &2), 10);
+        assert_eq!(*g.weight(&1, &3), 20);
+        // edges which don't exist also have the default weight
+        assert_eq!(*g.weight(&3, &1), 10);
+        assert_eq!(g.weights().len(), 2);
+        assert_eq!(
+            g.weighted_distances(1),
+            crate::map! {1 => 0, 2 => 10, 3 => 11}
+        );
+
+        // re-adding an edge replaces its weight
+        g.add_weighted_edge(1, 3, 5);
+        assert_eq!(g.edge_count(), 3);
+        assert_eq!(g.weighted_distances(1)[3], 5);
+
+        let g = vec![(1, 2, 3_u8), (2, 1, 4)]
+            .into_iter()
+            .collect::<EasyGraph<_, _>>();
+        assert_eq!(*g.default_weight(), 1);
+        assert_eq!(g.weighted_distances(2), crate::map! {2 => 0, 1 => 4});
+    }
+}
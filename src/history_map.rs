@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Deref, Index, IndexMut};
+
+use crate::EasyMap;
+
+/// A change to an `EasyHistoryMap`, stored as the key and its value before the change (`None` if it wasn't in the
+/// map).
+type Change<K, V> = (K, Option<V>);
+
+/// An `EasyMap` which records its changes, so they can be undone and redone. This is handy for interactive programs, and
+/// for backtracking searches which need to rewind their state.
+///
+/// ```rust
+/// use easy_collections::EasyHistoryMap;
+///
+/// let mut map = EasyHistoryMap::new();
+/// map['a'] = 1;
+/// map.insert('b', 2);
+/// map['a'] += 10;
+/// assert_eq!(map['a'], 11);
+/// map.undo();
+/// assert_eq!(map['a'], 1);
+/// map.undo();
+/// assert_eq!(map['b'], 0);
+/// map.redo();
+/// assert_eq!(map['b'], 2);
+/// ```
+///
+/// Checkpoints can be used to undo several changes at once:
+/// ```rust
+/// use easy_collections::EasyHistoryMap;
+///
+/// let mut board = EasyHistoryMap::new();
+/// board[(0, 0)] = 'x';
+/// let before_guess = board.checkpoint();
+/// board[(1, 1)] = 'o';
+/// board[(2, 2)] = 'x';
+/// assert!(board.rollback_to(before_guess));
+/// assert_eq!(board.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EasyHistoryMap<K: Eq + Hash + Clone, V> {
+    inner: EasyMap<K, V>,
+    // every change has an id, which increases along the undo stack, so checkpoints can tell whether they're still in
+    // the history
+    undo: Vec<(u64, Change<K, V>)>,
+    redo: Vec<(u64, Change<K, V>)>,
+    // the id of the state before the first change which can be undone
+    base: u64,
+    next_id: u64,
+}
+
+/// A point in the history of an `EasyHistoryMap`, returned by `EasyHistoryMap::checkpoint`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Checkpoint(u64);
+
+impl<K: Eq + Hash + Clone, V: Default> EasyHistoryMap<K, V> {
+    /// Create a new `EasyHistoryMap`. The value `V` must implement `Default`.
+    pub fn new() -> EasyHistoryMap<K, V> {
+        EasyMap::new().into()
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> EasyHistoryMap<K, V> {
+    /// Create a new `EasyHistoryMap`. The value `V` does not need to implement `Default`, instead you provide it with
+    /// one here.
+    pub fn new_with_default(default: V) -> EasyHistoryMap<K, V>
+    where
+        V: Clone,
+    {
+        EasyMap::new_with_default(default).into()
+    }
+
+    /// Records `k`'s current value, so the change which is about to happen can be undone. Any undone changes can no
+    /// longer be redone.
+    fn record(&mut self, k: &K)
+    where
+        V: Clone,
+    {
+        self.next_id += 1;
+        self.undo
+            .push((self.next_id, (k.clone(), self.inner.get(k).cloned())));
+        self.redo.clear();
+    }
+
+    /// Sets `k` to `v` (or removes it, for `None`), returning the change which reverses it.
+    fn apply(&mut self, (k, v): Change<K, V>) -> Change<K, V> {
+        let previous = match v {
+            Some(v) => self.inner.insert(k.clone(), v),
+            None => self.inner.remove(k.clone()),
+        };
+
+        (k, previous)
+    }
+
+    /// Same as `HashMap::insert`.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V>
+    where
+        V: Clone,
+    {
+        self.record(&k);
+        self.inner.insert(k, v)
+    }
+
+    /// Same as `HashMap::remove`. Removing a key which isn't in the map isn't recorded.
+    pub fn remove(&mut self, k: K) -> Option<V>
+    where
+        V: Clone,
+    {
+        if !self.inner.contains_key(&k) {
+            return None;
+        }
+
+        self.record(&k);
+        self.inner.remove(k)
+    }
+
+    /// Undoes the most recent change, returning whether there was one to undo.
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop() {
+            Some((id, change)) => {
+                let reverse = self.apply(change);
+                self.redo.push((id, reverse));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Redoes the most recently undone change, returning whether there was one to redo. Changes can only be redone
+    /// until the map is changed again.
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some((id, change)) => {
+                let reverse = self.apply(change);
+                self.undo.push((id, reverse));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a checkpoint of the current state, which can be returned to with `EasyHistoryMap::rollback_to`.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.undo.last().map_or(self.base, |(id, _)| *id))
+    }
+
+    /// Undoes every change since `checkpoint` was made, returning whether it could. The changes can be redone one at
+    /// a time with `EasyHistoryMap::redo`.
+    ///
+    /// Nothing is undone if the checkpoint is no longer in the undo history: because its changes were undone (and
+    /// maybe replaced by new ones), or because the history was cleared.
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) -> bool {
+        if checkpoint.0 != self.base && !self.undo.iter().any(|(id, _)| *id == checkpoint.0) {
+            return false;
+        }
+
+        while self.undo.last().is_some_and(|(id, _)| *id > checkpoint.0) {
+            self.undo();
+        }
+
+        true
+    }
+
+    /// Forgets every recorded change, so they can't be undone or redone. Checkpoints made before this can no longer be
+    /// rolled back to.
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.next_id += 1;
+        self.base = self.next_id;
+    }
+
+    /// Consumes the map, returning its current state without the history.
+    pub fn into_map(self) -> EasyMap<K, V> {
+        self.inner
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Default> Default for EasyHistoryMap<K, V> {
+    fn default() -> Self {
+        EasyHistoryMap::new()
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> From<EasyMap<K, V>> for EasyHistoryMap<K, V> {
+    fn from(inner: EasyMap<K, V>) -> Self {
+        EasyHistoryMap {
+            inner,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            base: 0,
+            next_id: 0,
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> Deref for EasyHistoryMap<K, V> {
+    type Target = HashMap<K, V>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> Index<K> for EasyHistoryMap<K, V> {
+    type Output = V;
+    fn index(&self, key: K) -> &Self::Output {
+        &self.inner[key]
+    }
+}
+
+/// Every mutable access is recorded as a change, even if the value isn't modified.
+impl<K: Eq + Hash + Clone, V: Clone> IndexMut<K> for EasyHistoryMap<K, V> {
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        self.record(&key);
+        &mut self.inner[key]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::map;
+
+    #[test]
+    fn undo_redo() {
+        let mut map = EasyHistoryMap::from(map! {-1; 'a' => 1});
+        assert!(!map.undo());
+        assert!(!map.redo());
+        map.insert('a', 2);
+        map['b'] = 3;
+        assert_eq!(map.remove('a'), Some(2));
+        assert_eq!(map.remove('z'), None);
+
+        assert!(map.undo());
+        assert_eq!(map['a'], 2);
+        assert!(map.undo());
+        assert_eq!(map['b'], -1);
+        assert!(!map.contains_key(&'b'));
+        assert!(map.undo());
+        assert_eq!(map['a'], 1);
+        assert!(!map.undo());
+
+        assert!(map.redo());
+        assert!(map.redo());
+        assert!(map.redo());
+        assert!(!map.redo());
+        assert_eq!(map.clone().into_map(), map! {-1; 'b' => 3});
+
+        map.undo();
+        map['c'] = 4;
+        assert!(!map.redo());
+    }
+
+    #[test]
+    fn checkpoints() {
+        let mut map = EasyHistoryMap::new();
+        let start = map.checkpoint();
+        map[1] = 'a';
+        let middle = map.checkpoint();
+        map[2] = 'b';
+        map[1] = 'c';
+        assert!(map.rollback_to(middle));
+        assert_eq!(map.clone().into_map(), map! {1 => 'a'});
+        assert!(map.rollback_to(middle));
+        assert_eq!(map.len(), 1);
+        assert!(map.rollback_to(start));
+        assert!(map.is_empty());
+        assert!(!map.rollback_to(middle));
+
+        map.redo();
+        let before_clear = map.checkpoint();
+        map.clear_history();
+        assert!(!map.undo());
+        assert_eq!(map[1], 'a');
+        assert!(!map.rollback_to(before_clear));
+        assert!(!map.rollback_to(start));
+        assert_eq!(map[1], 'a');
+
+        let after_clear = map.checkpoint();
+        map[3] = 'd';
+        assert!(map.rollback_to(after_clear));
+        assert_eq!(map.clone().into_map(), map! {1 => 'a'});
+    }
+
+    #[test]
+    fn checkpoint_after_branching() {
+        let mut map = EasyHistoryMap::new();
+        map[1] = 'a';
+        let start = map.checkpoint();
+        map[1] = 'b';
+        let c = map.checkpoint();
+        map.undo();
+        map[2] = 'b';
+        assert!(!map.rollback_to(c));
+        assert_eq!(map.clone().into_map(), map! {1 => 'a', 2 => 'b'});
+
+        let d = map.checkpoint();
+        map[3] = 'c';
+        assert!(map.rollback_to(d));
+        assert!(map.rollback_to(start));
+        assert_eq!(map.clone().into_map(), map! {1 => 'a'});
+    }
+}
@@ -0,0 +1,298 @@
+use std::hash::Hash;
+use std::ops::AddAssign;
+
+use crate::{EasyMap, EasySet};
+
+/// Methods for collecting any iterator into this crate's collections, without the turbofish.
+///
+/// ```rust
+/// use easy_collections::{map, set, EasyIteratorExt};
+///
+/// assert_eq!("hello".chars().collect_set(), set!{'h', 'e', 'l', 'o'});
+/// assert_eq!(vec![("a", 1)].into_iter().collect_map(), map!{"a" => 1});
+/// ```
+pub trait EasyIteratorExt: Iterator + Sized {
+    /// Collects the items into an `EasySet`.
+    fn collect_set(self) -> EasySet<Self::Item>
+    where
+        Self::Item: Eq + Hash,
+    {
+        self.collect()
+    }
+
+    /// Collects pairs into an `EasyMap`, whose default value is `V::default()`.
+    fn collect_map<K, V>(self) -> EasyMap<K, V>
+    where
+        Self: Iterator<Item = (K, V)>,
+        K: Eq + Hash,
+        V: Default,
+    {
+        self.collect()
+    }
+
+    /// Collects pairs into an `EasyMap` with the given default value.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyIteratorExt;
+    ///
+    /// let map = vec![('a', 1)].into_iter().collect_map_with_default(-1);
+    /// assert_eq!(map['a'], 1);
+    /// assert_eq!(map['b'], -1);
+    /// ```
+    fn collect_map_with_default<K, V>(self, default: V) -> EasyMap<K, V>
+    where
+        Self: Iterator<Item = (K, V)>,
+        K: Eq + Hash,
+        V: Clone,
+    {
+        let mut map = EasyMap::new_with_default(default);
+        map.extend(self);
+        map
+    }
+
+    /// Counts how many times each item appears.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyIteratorExt;
+    ///
+    /// let counts = "hello".chars().counts();
+    /// assert_eq!(counts['l'], 2);
+    /// assert_eq!(counts['z'], 0);
+    /// ```
+    fn counts(self) -> EasyMap<Self::Item, usize>
+    where
+        Self::Item: Eq + Hash,
+    {
+        let mut counts = EasyMap::new();
+        for item in self {
+            counts[item] += 1;
+        }
+
+        counts
+    }
+
+    /// Groups the items by the key from `key_fn`, keeping them in order within each group.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyIteratorExt;
+    ///
+    /// let by_len = vec!["a", "bb", "c"].into_iter().group_by_map(|s| s.len());
+    /// assert_eq!(by_len[1], vec!["a", "c"]);
+    /// assert!(by_len[3].is_empty());
+    /// ```
+    fn group_by_map<K, F>(self, mut key_fn: F) -> EasyMap<K, Vec<Self::Item>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut groups: EasyMap<K, Vec<Self::Item>> = EasyMap::new();
+        for item in self {
+            groups[key_fn(&item)].push(item);
+        }
+
+        groups
+    }
+
+    /// Creates a map of the items, keyed by `key_fn`. If several items have the same key, the last one wins.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyIteratorExt;
+    ///
+    /// let users = vec![(1, "alice"), (2, "bob")];
+    /// let by_id = users.into_iter().index_by(|(id, _)| *id);
+    /// assert_eq!(by_id[2], (2, "bob"));
+    /// ```
+    fn index_by<K, F>(self, mut key_fn: F) -> EasyMap<K, Self::Item>
+    where
+        K: Eq + Hash,
+        Self::Item: Default,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.map(|item| (key_fn(&item), item)).collect()
+    }
+
+    /// Groups the items by the key from `key_fn`, returning an `EasyGroupingMap` which aggregates each group into an
+    /// `EasyMap`.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyIteratorExt;
+    ///
+    /// let sales = vec![("north", 10), ("south", 5), ("north", 20)];
+    /// let totals = sales.into_iter().into_grouping_map_by(|(region, _)| *region).fold(0, |acc, _, (_, n)| acc + n);
+    /// assert_eq!(totals["north"], 30);
+    /// assert_eq!(totals["south"], 5);
+    /// assert_eq!(totals["east"], 0);
+    /// ```
+    fn into_grouping_map_by<K, F>(self, key_fn: F) -> EasyGroupingMap<Self, F>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        EasyGroupingMap { iter: self, key_fn }
+    }
+}
+
+impl<I: Iterator> EasyIteratorExt for I {}
+
+/// Items grouped by a key, created by `EasyIteratorExt::into_grouping_map_by`. Each method aggregates the items of
+/// every group, and returns an `EasyMap` from each key to its aggregate.
+pub struct EasyGroupingMap<I, F> {
+    iter: I,
+    key_fn: F,
+}
+
+impl<I, F, K> EasyGroupingMap<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: Eq + Hash,
+{
+    /// Folds the items of each group, starting from `init`. The function is called with the accumulator, the key
+    /// and the item. Keys which had no items return `init`.
+    pub fn fold<R: Clone>(
+        mut self,
+        init: R,
+        mut f: impl FnMut(R, &K, I::Item) -> R,
+    ) -> EasyMap<K, R> {
+        let mut map = EasyMap::new_with_default(init);
+        for item in self.iter {
+            let k = (self.key_fn)(&item);
+            let acc = map.inner.remove(&k).unwrap_or_else(|| map.fill_default());
+            let acc = f(acc, &k, item);
+            map.insert(k, acc);
+        }
+
+        map
+    }
+
+    /// Collects the items of each group into a `Vec`, in order.
+    pub fn collect_vec(self) -> EasyMap<K, Vec<I::Item>> {
+        self.iter.group_by_map(self.key_fn)
+    }
+
+    /// Counts the items of each group.
+    pub fn count(self) -> EasyMap<K, usize> {
+        let mut key_fn = self.key_fn;
+        self.iter.map(|item| key_fn(&item)).counts()
+    }
+
+    /// Sums the items of each group. Keys which had no items return `I::Item::default()`.
+    pub fn sum(mut self) -> EasyMap<K, I::Item>
+    where
+        I::Item: Default + AddAssign,
+    {
+        let mut map = EasyMap::new();
+        for item in self.iter {
+            map[(self.key_fn)(&item)] += item;
+        }
+
+        map
+    }
+
+    /// Finds the largest item of each group, the last one if several are equally large. Keys which had no items
+    /// return `I::Item::default()`.
+    pub fn max(self) -> EasyMap<K, I::Item>
+    where
+        I::Item: Default + Ord,
+    {
+        self.reduce(|acc, item| if item >= acc { item } else { acc })
+    }
+
+    /// Finds the smallest item of each group, the first one if several are equally small. Keys which had no items
+    /// return `I::Item::default()`.
+    pub fn min(self) -> EasyMap<K, I::Item>
+    where
+        I::Item: Default + Ord,
+    {
+        self.reduce(|acc, item| if item < acc { item } else { acc })
+    }
+
+    /// Reduces the items of each group with `f`, starting from the first item of the group.
+    fn reduce(mut self, mut f: impl FnMut(I::Item, I::Item) -> I::Item) -> EasyMap<K, I::Item>
+    where
+        I::Item: Default,
+    {
+        let mut map = EasyMap::new();
+        for item in self.iter {
+            let k = (self.key_fn)(&item);
+            let acc = match map.inner.remove(&k) {
+                Some(acc) => f(acc, item),
+                None => item,
+            };
+            map.insert(k, acc);
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{map, set};
+
+    #[test]
+    fn collect() {
+        assert_eq!(vec![1, 2, 2, 3].into_iter().collect_set(), set! {1, 2, 3});
+        assert!(std::iter::empty::<u8>().collect_set().is_empty());
+
+        let map = (0..3).map(|n| (n, n * 10)).collect_map();
+        assert_eq!(map, map! {0 => 0, 1 => 10, 2 => 20});
+        assert_eq!(map[5], 0);
+
+        let map = vec![("a", 1), ("a", 2)]
+            .into_iter()
+            .collect_map_with_default(7);
+        assert_eq!(map, map! {7; "a" => 2});
+    }
+
+    #[test]
+    fn tally() {
+        let counts = vec!["a", "b", "a"].into_iter().counts();
+        assert_eq!(counts, map! {"a" => 2, "b" => 1});
+
+        let groups = (1..=6).group_by_map(|n| n % 3);
+        assert_eq!(
+            groups,
+            map! {0 => vec![3, 6], 1 => vec![1, 4], 2 => vec![2, 5]}
+        );
+
+        let index = vec!["x", "yy", "zz"].into_iter().index_by(|s| s.len());
+        assert_eq!(index, map! {1 => "x", 2 => "zz"});
+        assert_eq!(index[3], "");
+    }
+
+    #[test]
+    fn grouping_map() {
+        let words = || vec!["apple", "avocado", "banana", "blueberry", "cherry"].into_iter();
+        let initial = |w: &&str| w.chars().next().unwrap();
+
+        let lengths = words()
+            .into_grouping_map_by(initial)
+            .fold(0, |acc, _, w| acc + w.len());
+        assert_eq!(lengths, map! {'a' => 12, 'b' => 15, 'c' => 6});
+        assert_eq!(
+            words().into_grouping_map_by(initial).collect_vec()['b'],
+            vec!["banana", "blueberry"]
+        );
+        assert_eq!(
+            words().into_grouping_map_by(initial).count(),
+            map! {'a' => 2, 'b' => 2, 'c' => 1}
+        );
+        assert_eq!(words().into_grouping_map_by(initial).max()['a'], "avocado");
+        assert_eq!(words().into_grouping_map_by(initial).min()['b'], "banana");
+        assert_eq!(words().into_grouping_map_by(initial).min()['z'], "");
+
+        let sums = (1..=10).into_grouping_map_by(|n| n % 2 == 0).sum();
+        assert_eq!(sums, map! {false => 25, true => 30});
+        let keyed = vec![(1, 'a'), (2, 'b'), (1, 'c')]
+            .into_iter()
+            .into_grouping_map_by(|(n, _)| *n)
+            .fold(String::new(), |mut acc, k, (_, c)| {
+                acc.push(c);
+                acc.push_str(&k.to_string());
+                acc
+            });
+        assert_eq!(keyed[1], "a1c1");
+    }
+}
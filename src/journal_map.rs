@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Deref, Index};
+
+use crate::EasyMap;
+
+/// A change recorded by an `EasyJournalMap`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ChangeEvent<K, V> {
+    /// A key which wasn't in the map was inserted.
+    Insert { key: K, value: V },
+    /// The value of a key which was already in the map was replaced.
+    Overwrite { key: K, old: V, new: V },
+    /// A key was removed from the map.
+    Remove { key: K, value: V },
+}
+
+/// An `EasyMap` which records every change made to it in a journal, for replaying or auditing them, or for working
+/// out what changed while debugging. Create one with `EasyMap::journaled`.
+///
+/// Since changes need to be recorded, values are changed with `EasyJournalMap::modify` rather than `IndexMut`.
+///
+/// ```rust
+/// use easy_collections::{map, ChangeEvent};
+///
+/// let mut map = map!{"a" => 1}.journaled();
+/// map.insert("b", 2);
+/// map.modify("a", |v| *v += 10);
+/// map.remove("b");
+/// assert_eq!(map["a"], 11);
+/// assert_eq!(map.take_journal(), vec![
+///     ChangeEvent::Insert { key: "b", value: 2 },
+///     ChangeEvent::Overwrite { key: "a", old: 1, new: 11 },
+///     ChangeEvent::Remove { key: "b", value: 2 },
+/// ]);
+/// assert!(map.take_journal().is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EasyJournalMap<K: Eq + Hash + Clone, V> {
+    inner: EasyMap<K, V>,
+    journal: Vec<ChangeEvent<K, V>>,
+}
+
+impl<K: Eq + Hash + Clone, V> EasyMap<K, V> {
+    /// Consumes the map, returning an `EasyJournalMap` which records the changes made to it from now on.
+    pub fn journaled(self) -> EasyJournalMap<K, V> {
+        EasyJournalMap {
+            inner: self,
+            journal: Vec::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> EasyJournalMap<K, V> {
+    /// Records that `key` was changed from `old` (`None` if it wasn't in the map) to `new`.
+    fn record(&mut self, key: K, old: Option<V>, new: V) {
+        self.journal.push(match old {
+            Some(old) => ChangeEvent::Overwrite { key, old, new },
+            None => ChangeEvent::Insert { key, value: new },
+        });
+    }
+
+    /// Same as `HashMap::insert`.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V>
+    where
+        V: Clone,
+    {
+        let old = self.inner.insert(k.clone(), v.clone());
+        self.record(k, old.clone(), v);
+        old
+    }
+
+    /// Same as `HashMap::remove`. Removing a key which isn't in the map isn't recorded.
+    pub fn remove(&mut self, k: K) -> Option<V>
+    where
+        V: Clone,
+    {
+        let value = self.inner.remove(k.clone())?;
+        self.journal.push(ChangeEvent::Remove {
+            key: k,
+            value: value.clone(),
+        });
+
+        Some(value)
+    }
+
+    /// Calls `f` with the value for `k`, inserting the default value first if it's not in the map, and records the
+    /// change.
+    pub fn modify<R>(&mut self, k: K, f: impl FnOnce(&mut V) -> R) -> R
+    where
+        V: Clone,
+    {
+        let old = self.inner.get(&k).cloned();
+        let value = &mut self.inner[k.clone()];
+        let result = f(value);
+        let new = value.clone();
+        self.record(k, old, new);
+
+        result
+    }
+
+    /// Returns the changes recorded since the journal was last taken, oldest first, and clears the journal.
+    pub fn take_journal(&mut self) -> Vec<ChangeEvent<K, V>> {
+        std::mem::take(&mut self.journal)
+    }
+
+    /// Consumes the map, returning its current state without the journal.
+    pub fn into_map(self) -> EasyMap<K, V> {
+        self.inner
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> Deref for EasyJournalMap<K, V> {
+    type Target = HashMap<K, V>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> Index<K> for EasyJournalMap<K, V> {
+    type Output = V;
+    fn index(&self, key: K) -> &Self::Output {
+        &self.inner[key]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::map;
+
+    #[test]
+    fn journal() {
+        let mut map = EasyMap::new_with_default(vec![0]).journaled();
+        assert_eq!(map.remove('z'), None);
+        map.modify('a', |v| v.push(1));
+        assert_eq!(map.insert('a', vec![2]), Some(vec![0, 1]));
+        assert_eq!(
+            map.take_journal(),
+            vec![
+                ChangeEvent::Insert {
+                    key: 'a',
+                    value: vec![0, 1]
+                },
+                ChangeEvent::Overwrite {
+                    key: 'a',
+                    old: vec![0, 1],
+                    new: vec![2]
+                },
+            ]
+        );
+
+        assert_eq!(map.modify('a', |v| v.len()), 1);
+        assert_eq!(map.remove('a'), Some(vec![2]));
+        assert_eq!(map.take_journal().len(), 2);
+        assert_eq!(map['a'], vec![0]);
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn replay() {
+        let mut map = map! {1 => 'a', 2 => 'b'}.journaled();
+        let start = map.clone().into_map();
+        map.insert(3, 'c');
+        map.remove(1);
+        map.modify(2, |v| *v = 'B');
+
+        let mut replayed = start;
+        for event in map.take_journal() {
+            match event {
+                ChangeEvent::Insert { key, value } => replayed[key] = value,
+                ChangeEvent::Overwrite { key, new, .. } => replayed[key] = new,
+                ChangeEvent::Remove { key, .. } => {
+                    replayed.remove(key);
+                }
+            }
+        }
+        assert_eq!(replayed, map.into_map());
+    }
+}
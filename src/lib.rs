@@ -53,12 +53,102 @@
 //! let hash: &HashMap<_, _> = &*easy;
 //! assert_eq!(&*easy, hash);
 //! ```
+//!
+//! # Features
+//!
+//! - `postcard`: adds `to_bytes` and `from_bytes` to `EasyMap` and `EasySet` for quick binary snapshots (implies `serde`)
+//! - `pyo3`: implements `FromPyObject` and `IntoPyObject` for `EasyMap` and `EasySet`, converting to and from Python's `dict` and `set`
+//! - `rand`: adds `EasySet::sample_from`, `EasyMap::sample_entries` and `EasyMap::shuffled_entries` for random sampling
+//! - `rayon`: implements `FromParallelIterator`, `ParallelExtend` and `IntoParallelIterator` for `EasyMap` and `EasySet`
+//! - `regex`: adds `EasyMap::keys_matching`, `EasyMap::retain_matching`, `EasySet::matching` and `EasySet::retain_matching` for querying string keys with a `Regex`
+//! - `ron`: adds `from_ron` to `EasyMap` and `EasySet` for loading fixtures from RON strings (implies `serde`)
+//! - `serde`: implements `Serialize` and `Deserialize` for `EasyMap` and `EasySet` (the default value of an `EasyMap` isn't serialized, `V::default()` is used when deserializing; use the `with_default` module to keep it)
+//! - `tokio`: adds `EasyAsyncMap`, an async cache which coalesces concurrent requests for the same key
+//! - `wasm`: conversions between `EasyMap`/`EasySet` and `js_sys::Map`/`js_sys::Set` (and `JsValue`); converting from JavaScript is fallible and returns the value which failed to convert as the error
 
+mod any_map;
+#[cfg(feature = "tokio")]
+mod async_map;
+mod bag;
+mod bloom;
+mod bounded_map;
+#[cfg(feature = "postcard")]
+mod bytes;
+mod cardinality;
+mod case_insensitive;
+mod concurrent_map;
+mod domain_set;
+mod entry_ref;
+mod glob;
+mod graph;
+mod history_map;
+mod iter;
+mod journal_map;
 mod map;
+mod matrix;
+mod memo;
+mod minhash;
+mod once_map;
+mod ordered_set;
+#[cfg(feature = "rayon")]
+mod par;
+mod path;
+#[cfg(feature = "pyo3")]
+mod py;
+mod queue;
+#[cfg(feature = "regex")]
+mod regex_impls;
+#[cfg(feature = "ron")]
+mod ron_impls;
+#[cfg(feature = "rand")]
+mod sample;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod set;
+mod sorted_map;
+mod sorted_set;
+mod stack;
+mod statics;
+mod substring_index;
+mod transaction;
+mod value;
+mod vec;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "serde")]
+pub mod with_default;
 
 pub use map as easy_collections;
 pub use set as easy_set;
 
-pub use map::EasyMap;
-pub use set::EasySet;
+pub use any_map::EasyAnyMap;
+#[cfg(feature = "tokio")]
+pub use async_map::EasyAsyncMap;
+pub use bag::EasyBag;
+pub use bloom::EasyBloom;
+pub use bounded_map::{EasyBoundedMap, EvictionPolicy};
+pub use cardinality::EasyCardinality;
+pub use case_insensitive::CaseInsensitive;
+pub use concurrent_map::EasyConcurrentMap;
+pub use domain_set::{EasyDomainSet, NotInDomain};
+pub use entry_ref::EasyEntryRef;
+pub use graph::{Cycle, EasyGraph};
+pub use history_map::{Checkpoint, EasyHistoryMap};
+pub use iter::{EasyGroupingMap, EasyIteratorExt};
+pub use journal_map::{ChangeEvent, EasyJournalMap};
+pub use map::{pivot, EasyMap};
+pub use matrix::EasyMatrix;
+pub use memo::{EasyMemo, EasyMemoHandle};
+pub use minhash::MinHashSignature;
+pub use once_map::{EasyOnceMap, Occupied};
+pub use ordered_set::EasyOrderedSet;
+pub use path::NestedValue;
+pub use queue::EasyQueue;
+pub use set::{membership, EasySet, SetOperand};
+pub use sorted_map::EasySortedMap;
+pub use sorted_set::EasySortedSet;
+pub use stack::EasyStack;
+pub use substring_index::EasySubstringIndex;
+pub use transaction::EasyTransaction;
+pub use value::EasyValue;
+pub use vec::EasyVec;
@@ -1,11 +1,79 @@
-use std::collections::hash_map::Entry;
+use crate::set::private::OperandSet;
+use crate::{EasySet, SetOperand};
+use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
+use std::collections::hash_map::{Drain, Entry};
 use std::collections::HashMap;
+use std::fmt::{self, Debug, Display};
 use std::hash::Hash;
 use std::iter::FromIterator;
-use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::ops::{AddAssign, BitOr, BitOrAssign, Deref, DerefMut, Index, IndexMut, SubAssign};
+use std::str::FromStr;
+use std::sync::Arc;
 
+/// Creates an `EasyMap`, optionally with a default value and some entries.
+///
+/// The values can be other `map!` literals, and their types are inferred from the outer map, so nested data can be
+/// written inline. Missing keys of the outer map return an empty inner map, which has the inner default value.
+/// ```rust
+/// use easy_collections::map;
+///
+/// let config = map!{"db" => map!{"host" => "localhost", "port" => "5432"}, "cache" => map!{}};
+/// assert_eq!(config["db"]["host"], "localhost");
+/// assert_eq!(config["cache"]["host"], "");
+/// assert_eq!(config["nope"]["host"], "");
+/// ```
+///
+/// If the outer map has a default value, nested `map!` literals without a default of their own use the default of the
+/// outer map's default value, at every level. This requires the inner values to implement `Clone`, and the entries
+/// to be separated by commas.
+/// ```rust
+/// use easy_collections::map;
+///
+/// let grid = map!{map!{-1}; 0 => map!{0 => 5}, 1 => map!{7; 0 => 6}};
+/// assert_eq!(grid[0][0], 5);
+/// assert_eq!(grid[0][1], -1);
+/// assert_eq!(grid[1][1], 7);
+/// assert_eq!(grid[2][0], -1);
+/// ```
+///
+/// Starting with `into;` converts every key with `Into`, for example so string literals can be used as the keys of an
+/// `EasyMap<Cow<'static, str>, V>`. This needs the key type to be known, so it can't be inferred from the keys.
+/// ```rust
+/// use easy_collections::{map, EasyMap};
+/// use std::borrow::Cow;
+///
+/// let map: EasyMap<Cow<str>, usize> = map!{into; "literal" => 1, String::from("owned") => 2};
+/// assert_eq!(map["literal"], 1);
+/// assert_eq!(map["owned"], 2);
+/// ```
 #[macro_export]
 macro_rules! map {
+    // inserts the entries into `$map`, giving nested literals the default of `$map`'s default value
+    (@insert $map:ident;) => {};
+    (@insert $map:ident; $key:expr => map!{$($inner:tt)*} $(, $($rest:tt)*)?) => {
+        $map.insert($key, $crate::map!(@inner $map; $($inner)*));
+        $($crate::map!(@insert $map; $($rest)*);)?
+    };
+    (@insert $map:ident; $key:expr => $val:expr $(, $($rest:tt)*)?) => {
+        $map.insert($key, $val);
+        $($crate::map!(@insert $map; $($rest)*);)?
+    };
+    (@inner $parent:ident;) => {
+        $crate::EasyMap::new_with_default($parent.default_value().default_value().clone())
+    };
+    (@inner $parent:ident; $default:expr) => {
+        $crate::map!{$default}
+    };
+    (@inner $parent:ident; $default:expr; $($rest:tt)*) => {
+        $crate::map!{$default; $($rest)*}
+    };
+    (@inner $parent:ident; $($rest:tt)*) => {{
+        let mut inner = $crate::map!(@inner $parent;);
+        $crate::map!(@insert inner; $($rest)*);
+        inner
+    }};
+
     () => {
         $crate::EasyMap::new()
     };
@@ -13,14 +81,19 @@ macro_rules! map {
         $crate::EasyMap::new_with_default($default)
     };
 
+    {into; $($key:expr => $val:expr$(,)?)+} => {{
+        let mut map = $crate::map!{};
+        $(map.insert(::std::convert::Into::into($key), $val);)+
+        map
+    }};
     {$($key:expr => $val:expr$(,)?)*} => {{
-        let mut map = map!{};
-        $(map[$key] = $val;)*
+        let mut map = $crate::map!{};
+        $(map.insert($key, $val);)*
         map
     }};
-    {$default:expr; $($key:expr => $val:expr$(,)?)*} => {{
-        let mut map = map!{$default};
-        $(map[$key] = $val;)*
+    {$default:expr; $($rest:tt)*} => {{
+        let mut map = $crate::map!{$default};
+        $crate::map!(@insert map; $($rest)*);
         map
     }};
 }
@@ -38,14 +111,67 @@ macro_rules! map {
 /// map['a'] = 42_usize;
 /// assert_eq!(map['a'], 42);
 /// ```
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub struct EasyMap<K: Eq + Hash, V: Clone> {
-    inner: HashMap<K, V>,
-    default: V,
+///
+/// There's no raw entry API for looking up keys by a precomputed hash, since `HashMap` doesn't provide one on stable
+/// Rust. If the same key is looked up in several maps, build one key which is cheap to hash (such as an interned id)
+/// instead.
+pub struct EasyMap<K: Eq + Hash, V> {
+    pub(crate) inner: HashMap<K, V>,
+    pub(crate) default: V,
+    pub(crate) fill: Fill<V>,
+}
+
+/// How an `EasyMap` creates the values for keys which are written to before they're in the map.
+pub(crate) enum Fill<V> {
+    /// Clones the default value, with `V::clone`.
+    Value(fn(&V) -> V),
+    /// Calls a function, such as `V::default`, so values don't need to implement `Clone`.
+    Factory(fn() -> V),
+    /// Calls a closure, which may capture its environment.
+    Closure(Arc<dyn Fn() -> V + Send + Sync>),
+}
+
+impl<V> Clone for Fill<V> {
+    fn clone(&self) -> Self {
+        match self {
+            Fill::Value(clone) => Fill::Value(*clone),
+            Fill::Factory(f) => Fill::Factory(*f),
+            Fill::Closure(f) => Fill::Closure(Arc::clone(f)),
+        }
+    }
+}
+
+impl<V> Fill<V> {
+    /// Returns a new value, given the map's default value.
+    pub(crate) fn make(&self, default: &V) -> V {
+        match self {
+            Fill::Value(clone) => clone(default),
+            Fill::Factory(f) => f(),
+            Fill::Closure(f) => f(),
+        }
+    }
 }
 
-impl<K: Eq + Hash, V: Clone + Default> EasyMap<K, V> {
-    /// Create a new `EasyMap`. The value `V` must implement `Default`.
+impl<K: Eq + Hash + Clone, V: Clone> Clone for EasyMap<K, V> {
+    fn clone(&self) -> Self {
+        EasyMap {
+            inner: self.inner.clone(),
+            default: self.default.clone(),
+            fill: self.fill.clone(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq> PartialEq for EasyMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner && self.default == other.default
+    }
+}
+
+impl<K: Eq + Hash, V: Eq> Eq for EasyMap<K, V> {}
+
+impl<K: Eq + Hash, V: Default> EasyMap<K, V> {
+    /// Create a new `EasyMap`. The value `V` must implement `Default`, but it doesn't need to implement `Clone`.
     ///
     /// Note, that there are macros which make this easier:
     /// ```rust
@@ -64,13 +190,66 @@ impl<K: Eq + Hash, V: Clone + Default> EasyMap<K, V> {
     /// assert_eq!(map["hello"], "world");
     /// assert_eq!(map["not here"], "");
     /// ```
+    ///
+    /// Values which can't be cloned work too, since new entries are created with `V::default()`:
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    /// use std::sync::Mutex;
+    ///
+    /// let mut locks: EasyMap<&str, Mutex<u32>> = EasyMap::new();
+    /// *locks["a"].get_mut().unwrap() += 1;
+    /// assert_eq!(*locks["a"].lock().unwrap(), 1);
+    /// assert_eq!(*locks["b"].lock().unwrap(), 0);
+    /// ```
     pub fn new() -> EasyMap<K, V> {
-        EasyMap::new_with_default(V::default())
+        EasyMap {
+            inner: HashMap::new(),
+            default: V::default(),
+            fill: Fill::Factory(V::default),
+        }
+    }
+
+    /// Creates a map from an iterator, where each item is keyed by `key_fn`. If several items have the same key, the
+    /// last one wins. See `EasyMap::index_all_by` to keep all of them.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let words = vec!["apple", "banana", "avocado"];
+    /// let by_initial = EasyMap::index_by(words, |w| w.chars().next().unwrap());
+    /// assert_eq!(by_initial['a'], "avocado");
+    /// assert_eq!(by_initial['b'], "banana");
+    /// assert_eq!(by_initial['c'], "");
+    /// ```
+    pub fn index_by<I, F>(iter: I, mut key_fn: F) -> EasyMap<K, V>
+    where
+        I: IntoIterator<Item = V>,
+        F: FnMut(&V) -> K,
+    {
+        iter.into_iter().map(|v| (key_fn(&v), v)).collect()
+    }
+
+    /// Creates a map from an iterator of fallible entries, stopping at the first error and returning it. This is the
+    /// same as collecting into a `Result<EasyMap<K, V>, E>`, which also works.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let parse = |(k, v): (char, &str)| v.parse::<u32>().map(|v| (k, v));
+    /// let map = EasyMap::try_from_iter(vec![('a', "1"), ('b', "2")].into_iter().map(parse)).unwrap();
+    /// assert_eq!(map['b'], 2);
+    /// assert!(EasyMap::try_from_iter(vec![('a', "1"), ('b', "two")].into_iter().map(parse)).is_err());
+    /// ```
+    pub fn try_from_iter<E>(
+        iter: impl IntoIterator<Item = Result<(K, V), E>>,
+    ) -> Result<EasyMap<K, V>, E> {
+        iter.into_iter().collect()
     }
 }
 
 impl<K: Eq + Hash, V: Clone> EasyMap<K, V> {
     /// Create a new `EasyMap`. The value `V` does not need to implement `Default`, instead you provide it with one here.
+    /// New entries are created by cloning it.
     ///
     /// Note, that there's a macro which makes this easier:
     /// ```rust
@@ -100,6 +279,101 @@ impl<K: Eq + Hash, V: Clone> EasyMap<K, V> {
         EasyMap {
             inner: HashMap::new(),
             default,
+            fill: Fill::Value(V::clone),
+        }
+    }
+
+    /// Changes the default value, returning the previous one. Existing entries are unaffected, and new entries are
+    /// created by cloning it.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{42; "foo" => 1};
+    /// assert_eq!(map.set_default(0), 42);
+    /// assert_eq!(map["bar"], 0);
+    /// ```
+    pub fn set_default(&mut self, default: V) -> V {
+        self.fill = Fill::Value(V::clone);
+        std::mem::replace(&mut self.default, default)
+    }
+
+    /// Returns the map with its default value changed. This is useful for maps created with `collect`, since they
+    /// always use `V::default()`.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)].into_iter().collect::<EasyMap<_, _>>().with_default(-1);
+    /// assert_eq!(map["a"], 1);
+    /// assert_eq!(map["z"], -1);
+    /// ```
+    pub fn with_default(mut self, default: V) -> EasyMap<K, V> {
+        self.set_default(default);
+        self
+    }
+}
+
+impl<K: Eq + Hash, V> EasyMap<K, V> {
+    /// Create a new `EasyMap` whose new entries are created by calling `factory`, so `V` doesn't need to implement
+    /// `Clone` or `Default`. Reading keys which aren't in the map returns a value which was created by `factory` when
+    /// the map was. The factory can capture its environment, and is shared by the maps cloned from this one.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    /// use std::sync::mpsc::{channel, Receiver};
+    ///
+    /// struct Inbox(Option<Receiver<u8>>);
+    ///
+    /// let mut inboxes: EasyMap<&str, Inbox> = EasyMap::new_with_factory(|| Inbox(None));
+    /// let (tx, rx) = channel();
+    /// inboxes["alice"].0 = Some(rx);
+    /// tx.send(1).unwrap();
+    /// assert_eq!(inboxes["alice"].0.as_ref().unwrap().recv(), Ok(1));
+    /// assert!(inboxes["bob"].0.is_none());
+    ///
+    /// let prefix = String::from("user-");
+    /// let mut names: EasyMap<u32, String> = EasyMap::new_with_factory(move || prefix.clone());
+    /// names[1].push('1');
+    /// assert_eq!(names[1], "user-1");
+    /// assert_eq!(names[2], "user-");
+    /// ```
+    pub fn new_with_factory(factory: impl Fn() -> V + Send + Sync + 'static) -> EasyMap<K, V> {
+        EasyMap {
+            inner: HashMap::new(),
+            default: factory(),
+            fill: Fill::Closure(Arc::new(factory)),
+        }
+    }
+
+    /// Returns the default value, which is returned when reading keys which aren't in the map.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let map = map!{42; "foo" => 1};
+    /// assert_eq!(*map.default_value(), 42);
+    /// ```
+    pub fn default_value(&self) -> &V {
+        &self.default
+    }
+
+    /// Returns the value for `k`, or the default value if it's not in the map.
+    pub(crate) fn get_or_default(&self, k: &K) -> &V {
+        self.inner.get(k).unwrap_or(&self.default)
+    }
+
+    /// Returns a new value for a key which is written to before it's in the map.
+    pub(crate) fn fill_default(&self) -> V {
+        self.fill.make(&self.default)
+    }
+
+    /// Create a new, empty `EasyMap` with the same default value as this one.
+    pub(crate) fn new_like(&self) -> EasyMap<K, V> {
+        EasyMap {
+            inner: HashMap::new(),
+            default: self.fill_default(),
+            fill: self.fill.clone(),
         }
     }
 
@@ -116,72 +390,1334 @@ impl<K: Eq + Hash, V: Clone> EasyMap<K, V> {
         self.inner.insert(k, v)
     }
 
+    /// Inserts every entry from `other` into the map, overwriting any existing values, the same as Python's
+    /// `dict.update`. Like the `|=` operator, `other` can be anything that implements `Into<EasyMap<K, V>>`; to insert
+    /// the entries of an iterator, use `extend`.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{"a" => 1, "b" => 2};
+    /// map.update(vec![("b", 20), ("c", 30)]);
+    /// map.update(&[("d", 40)][..]);
+    /// map.update(map!{"e" => 50});
+    /// assert_eq!(map, map!{"a" => 1, "b" => 20, "c" => 30, "d" => 40, "e" => 50});
+    /// ```
+    pub fn update(&mut self, other: impl Into<EasyMap<K, V>>) {
+        self.inner.extend(other.into().inner);
+    }
+
     /// Same as `HashMap::remove`.
     pub fn remove(&mut self, k: K) -> Option<V> {
         self.inner.remove(&k)
     }
 
-    /// Same as `HashMap::entry`.
-    pub fn entry(&mut self, k: K) -> Entry<K, V> {
-        self.inner.entry(k)
+    /// Returns the map with `k` set to `v`. Along with `EasyMap::without`, this lets you build variations of a map
+    /// inside expressions.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let base = map!{"host" => "localhost", "port" => "80"};
+    /// let custom = base.clone().with("port", "8080").without(&"host");
+    /// assert_eq!(custom, map!{"port" => "8080"});
+    /// assert_eq!(base["port"], "80");
+    /// ```
+    pub fn with(mut self, k: K, v: V) -> EasyMap<K, V> {
+        self.inner.insert(k, v);
+        self
     }
-}
 
-impl<K: Eq + Hash, V: Clone + Default> From<Vec<(K, V)>> for EasyMap<K, V> {
-    fn from(v: Vec<(K, V)>) -> Self {
-        v.into_iter().collect()
+    /// Returns the map with `k` removed.
+    pub fn without(mut self, k: &K) -> EasyMap<K, V> {
+        self.inner.remove(k);
+        self
     }
-}
 
-impl<K: Eq + Hash + Clone, V: Clone + Default> From<&[(K, V)]> for EasyMap<K, V> {
-    fn from(v: &[(K, V)]) -> Self {
-        v.iter().cloned().collect()
+    /// Moves the value of `old` to the key `new`, and returns whether it was moved. Nothing changes if `old` isn't in
+    /// the map, or if `new` is already in the map. See `EasyMap::replace_key` to overwrite `new` instead.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{"tmp" => 1, "taken" => 2};
+    /// assert!(map.rename_key(&"tmp", "final"));
+    /// assert!(!map.rename_key(&"final", "taken"));
+    /// assert!(!map.rename_key(&"missing", "other"));
+    /// assert_eq!(map, map!{"final" => 1, "taken" => 2});
+    /// ```
+    pub fn rename_key(&mut self, old: &K, new: K) -> bool {
+        if !self.inner.contains_key(old) {
+            return false;
+        }
+        if *old == new {
+            return true;
+        }
+        if self.inner.contains_key(&new) {
+            return false;
+        }
+
+        let v = self.inner.remove(old).unwrap();
+        self.inner.insert(new, v);
+        true
     }
-}
 
-impl<K: Eq + Hash, V: Clone + Default> FromIterator<(K, V)> for EasyMap<K, V> {
-    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
-        let mut set = map!(V::default());
-        for (k, v) in iter {
-            set.insert(k, v);
+    /// Moves the value of `old` to the key `new`, overwriting and returning any value which `new` had. Nothing changes
+    /// if `old` isn't in the map.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{"draft" => 2, "final" => 1};
+    /// assert_eq!(map.replace_key(&"draft", "final"), Some(1));
+    /// assert_eq!(map.replace_key(&"draft", "final"), None);
+    /// assert_eq!(map, map!{"final" => 2});
+    /// ```
+    pub fn replace_key(&mut self, old: &K, new: K) -> Option<V> {
+        if *old == new {
+            return None;
         }
 
-        set
+        let v = self.inner.remove(old)?;
+        self.inner.insert(new, v)
+    }
+
+    /// Removes the key from the map, returning its value if it was present.
+    /// This is the same as Python's `dict.pop(k)`.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{"foo" => 1};
+    /// assert_eq!(map.pop("foo"), Some(1));
+    /// assert_eq!(map.pop("foo"), None);
+    /// ```
+    pub fn pop(&mut self, k: K) -> Option<V> {
+        self.inner.remove(&k)
+    }
+
+    /// Removes the key from the map, returning its value or `fallback` if it wasn't present.
+    /// This is the same as Python's `dict.pop(k, default)`.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{"foo" => 1};
+    /// assert_eq!(map.pop_or("foo", 42), 1);
+    /// assert_eq!(map.pop_or("foo", 42), 42);
+    /// ```
+    pub fn pop_or(&mut self, k: K, fallback: V) -> V {
+        self.inner.remove(&k).unwrap_or(fallback)
+    }
+
+    /// Same as `HashMap::drain`. The map keeps its default value and its allocated capacity.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{42; "foo" => 1};
+    /// assert_eq!(map.drain().collect::<Vec<_>>(), vec![("foo", 1)]);
+    /// assert!(map.is_empty());
+    /// assert_eq!(map["foo"], 42);
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        self.inner.drain()
+    }
+
+    /// Moves every entry of the map into `other`, overwriting any existing values there. The map keeps its default
+    /// value and its allocated capacity, so it can be reused.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut batch = map!{"a" => 1, "b" => 2};
+    /// let mut totals = map!{"b" => 20, "c" => 30};
+    /// batch.drain_into(&mut totals);
+    /// assert!(batch.is_empty());
+    /// assert_eq!(totals, map!{"a" => 1, "b" => 2, "c" => 30});
+    /// ```
+    pub fn drain_into(&mut self, other: &mut EasyMap<K, V>) {
+        other.inner.extend(self.inner.drain());
+    }
+
+    /// Applies `f` to the value at `k`, creating it from the default value first if it doesn't exist.
+    /// Returns a mutable reference to the updated value.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{vec![1]; "foo" => vec![]};
+    /// map.apply("foo", |v| v.push(2));
+    /// map.apply("bar", |v| v.push(2));
+    /// assert_eq!(map["foo"], vec![2]);
+    /// assert_eq!(map["bar"], vec![1, 2]);
+    /// ```
+    pub fn apply(&mut self, k: K, f: impl FnOnce(&mut V)) -> &mut V {
+        let (default, fill) = (&self.default, &self.fill);
+        let v = self.inner.entry(k).or_insert_with(|| fill.make(default));
+        f(v);
+        v
+    }
+
+    /// Returns mutable references to the values of several keys at once, or `None` if any key is missing or if any
+    /// keys are duplicated.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut accounts = map!{"alice" => 100, "bob" => 50};
+    /// if let Some([alice, bob]) = accounts.get_many_mut([&"alice", &"bob"]) {
+    ///     *alice -= 30;
+    ///     *bob += 30;
+    /// }
+    /// assert_eq!(accounts["alice"], 70);
+    /// assert_eq!(accounts["bob"], 80);
+    ///
+    /// assert!(accounts.get_many_mut([&"alice", &"alice"]).is_none());
+    /// assert!(accounts.get_many_mut([&"alice", &"carol"]).is_none());
+    /// ```
+    pub fn get_many_mut<const N: usize>(&mut self, ks: [&K; N]) -> Option<[&mut V; N]> {
+        for (i, k) in ks.iter().enumerate() {
+            if ks[..i].contains(k) {
+                return None;
+            }
+        }
+
+        let values = self.inner.get_disjoint_mut(ks);
+        if values.iter().any(Option::is_none) {
+            return None;
+        }
+
+        Some(values.map(Option::unwrap))
+    }
+
+    /// Consumes the map, splitting it into the entries which match `pred` and the entries which don't. Both maps
+    /// have the same default value as this one.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let map = map!{-1; "a" => 1, "b" => 2, "c" => 3};
+    /// let (odd, even) = map.partition(|_, v| v % 2 == 1);
+    /// assert_eq!(odd, map!{-1; "a" => 1, "c" => 3});
+    /// assert_eq!(even, map!{-1; "b" => 2});
+    /// assert_eq!(even["a"], -1);
+    /// ```
+    pub fn partition(self, mut pred: impl FnMut(&K, &V) -> bool) -> (EasyMap<K, V>, EasyMap<K, V>) {
+        let mut matched = self.new_like();
+        let mut unmatched = self.new_like();
+        for (k, v) in self.inner {
+            if pred(&k, &v) {
+                matched.insert(k, v);
+            } else {
+                unmatched.insert(k, v);
+            }
+        }
+
+        (matched, unmatched)
+    }
+
+    /// Removes the entries for `keys` from the map, and returns them in a new map with the same default value.
+    ///
+    /// ```rust
+    /// use easy_collections::{map, set};
+    ///
+    /// let mut map = map!{"a" => 1, "b" => 2, "c" => 3};
+    /// let split = map.split_off_keys(&set!{"a", "c", "d"});
+    /// assert_eq!(map, map!{"b" => 2});
+    /// assert_eq!(split, map!{"a" => 1, "c" => 3});
+    /// ```
+    pub fn split_off_keys<Q: Borrow<K>>(
+        &mut self,
+        keys: impl IntoIterator<Item = Q>,
+    ) -> EasyMap<K, V> {
+        let mut split = self.new_like();
+        for k in keys {
+            if let Some((k, v)) = self.inner.remove_entry(k.borrow()) {
+                split.insert(k, v);
+            }
+        }
+
+        split
+    }
+
+    /// Consumes the map, grouping it into nested maps by splitting each key into an outer and an inner key. The inner
+    /// maps have the same default value as this map, and the outer map's default is an empty inner map.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let sales = map!{("2021", "jan") => 10, ("2021", "feb") => 20, ("2022", "jan") => 5};
+    /// let by_year = sales.nest_by(|k| k);
+    /// assert_eq!(by_year["2021"], map!{"jan" => 10, "feb" => 20});
+    /// assert_eq!(by_year["2022"]["feb"], 0);
+    /// assert_eq!(by_year["2023"]["jan"], 0);
+    /// ```
+    pub fn nest_by<K1, K2, F>(self, mut f: F) -> EasyMap<K1, EasyMap<K2, V>>
+    where
+        K1: Eq + Hash,
+        K2: Eq + Hash + Clone,
+        V: Clone,
+        F: FnMut(K) -> (K1, K2),
+    {
+        let template = EasyMap {
+            inner: HashMap::new(),
+            default: self.default,
+            fill: self.fill.clone(),
+        };
+        let mut nested = EasyMap::new_with_default(template);
+        for (k, v) in self.inner {
+            let (outer, inner) = f(k);
+            nested[outer].insert(inner, v);
+        }
+
+        nested
+    }
+
+    /// Consumes the map, converting each value (and the default value) into `V2` with `From`.
+    ///
+    /// ```rust
+    /// use easy_collections::{map, EasyMap};
+    ///
+    /// let tallies = map!{"hit" => 3_u32, "miss" => 1};
+    /// let tallies: EasyMap<_, f64> = tallies.map_into();
+    /// assert_eq!(tallies["hit"] / 4.0, 0.75);
+    /// assert_eq!(tallies["skip"], 0.0);
+    /// ```
+    pub fn map_into<V2: Clone + From<V>>(self) -> EasyMap<K, V2> {
+        EasyMap {
+            inner: self.inner.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            default: self.default.into(),
+            fill: Fill::Value(V2::clone),
+        }
+    }
+
+    /// Consumes the map, converting each value with `f` and stopping at the first error. The new map's default value
+    /// is `V2::default()`, since the old default might not convert.
+    ///
+    /// ```rust
+    /// use easy_collections::{map, EasyMap};
+    ///
+    /// let raw = map!{"width" => "80", "height" => "24"};
+    /// let parsed = raw.try_map_values(|v| v.parse::<u32>()).unwrap();
+    /// assert_eq!(parsed["width"], 80);
+    /// assert_eq!(parsed["depth"], 0);
+    ///
+    /// let raw = map!{"width" => "wide"};
+    /// assert!(raw.try_map_values(|v| v.parse::<u32>()).is_err());
+    /// ```
+    pub fn try_map_values<V2, E, F>(self, mut f: F) -> Result<EasyMap<K, V2>, E>
+    where
+        V2: Clone + Default,
+        F: FnMut(V) -> Result<V2, E>,
+    {
+        self.inner
+            .into_iter()
+            .map(|(k, v)| f(v).map(|v| (k, v)))
+            .collect()
+    }
+
+    /// Same as `HashMap::entry`.
+    pub fn entry(&mut self, k: K) -> Entry<'_, K, V> {
+        self.inner.entry(k)
     }
 }
 
-impl<K: Eq + Hash, V: Clone> IntoIterator for EasyMap<K, V> {
-    type Item = (K, V);
-    type IntoIter = std::collections::hash_map::IntoIter<K, V>;
+impl<K: Eq + Hash + Clone, V> EasyMap<K, V> {
+    /// Removes and returns an arbitrary entry from the map, or `None` if it's empty.
+    /// This is the same as Python's `dict.popitem()`, and is handy for worklist loops:
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{"foo" => 1, "bar" => 2};
+    /// let mut total = 0;
+    /// while let Some((_, v)) = map.pop_entry() {
+    ///     total += v;
+    /// }
+    /// assert_eq!(total, 3);
+    /// assert!(map.is_empty());
+    /// ```
+    ///
+    /// Finding the entry scans the map's table from the start, which doesn't shrink as entries are removed, so each
+    /// call takes time proportional to the map's capacity. Emptying a large map this way is quadratic; if no entries
+    /// are added along the way, use `EasyMap::drain` instead.
+    pub fn pop_entry(&mut self) -> Option<(K, V)> {
+        let k = self.inner.keys().next()?.clone();
+        self.inner.remove_entry(&k)
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.inner.into_iter()
+    /// Returns a copy of the entries of the map behind an `Arc`, which can be shared with other threads while this
+    /// map keeps changing.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    /// use std::thread;
+    ///
+    /// let mut map = map!{"jobs" => 1};
+    /// let snapshot = map.snapshot();
+    /// let reader = thread::spawn(move || snapshot["jobs"]);
+    /// map["jobs"] += 1;
+    /// assert_eq!(reader.join().unwrap(), 1);
+    /// assert_eq!(map["jobs"], 2);
+    /// ```
+    pub fn snapshot(&self) -> Arc<HashMap<K, V>>
+    where
+        V: Clone,
+    {
+        Arc::new(self.inner.clone())
+    }
+
+    /// Calls `f` with the value for `k`, inserting the default value first if it's not in the map, and returns the
+    /// result of `f`. The key is only cloned when it's inserted.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let words = vec![String::from("a"), String::from("b"), String::from("a")];
+    /// let mut positions = EasyMap::new();
+    /// for (i, word) in words.iter().enumerate() {
+    ///     positions.modify(word, |v: &mut Vec<_>| v.push(i));
+    /// }
+    /// assert_eq!(positions[&words[0]], vec![0, 2]);
+    /// assert_eq!(positions.modify(&words[1], |v| v.len()), 1);
+    /// ```
+    pub fn modify<R>(&mut self, k: &K, f: impl FnOnce(&mut V) -> R) -> R {
+        f(&mut self[k])
+    }
+
+    /// Swaps the values of `a` and `b`, inserting the default value first for either key that's not in the map.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut seats = map!{"empty"; 1 => "alice", 2 => "bob"};
+    /// seats.swap(&1, &2);
+    /// seats.swap(&2, &3);
+    /// assert_eq!(seats, map!{"empty"; 1 => "bob", 2 => "empty", 3 => "alice"});
+    /// ```
+    pub fn swap(&mut self, a: &K, b: &K) {
+        if a == b {
+            self.modify(a, |_| ());
+            return;
+        }
+
+        let va = self.inner.remove(a).unwrap_or_else(|| self.fill_default());
+        let vb = self.inner.remove(b).unwrap_or_else(|| self.fill_default());
+        self.inner.insert(a.clone(), vb);
+        self.inner.insert(b.clone(), va);
+    }
+
+    /// Returns the entries whose keys are in both maps, pairing up their values. The default value of the result is
+    /// made from the default values of both maps.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let names = map!{1 => "alice", 2 => "bob"};
+    /// let ages = map!{1 => 30, 3 => 40};
+    /// assert_eq!(names.join(&ages), map!{("", 0); 1 => ("alice", 30)});
+    /// assert_eq!(names.left_join(&ages), map!{("", 0); 1 => ("alice", 30), 2 => ("bob", 0)});
+    /// ```
+    pub fn join<W: Clone>(&self, other: &EasyMap<K, W>) -> EasyMap<K, (V, W)>
+    where
+        V: Clone,
+    {
+        let mut joined = EasyMap::new_with_default((self.default.clone(), other.default.clone()));
+        for (k, v) in &self.inner {
+            if let Some(w) = other.inner.get(k) {
+                joined.inner.insert(k.clone(), (v.clone(), w.clone()));
+            }
+        }
+
+        joined
+    }
+
+    /// Returns every entry of this map, paired with the value for its key in `other`, or the default value of `other`
+    /// if it's not there.
+    pub fn left_join<W: Clone>(&self, other: &EasyMap<K, W>) -> EasyMap<K, (V, W)>
+    where
+        V: Clone,
+    {
+        let mut joined = EasyMap::new_with_default((self.default.clone(), other.default.clone()));
+        for (k, v) in &self.inner {
+            let w = other.get_or_default(k).clone();
+            joined.inner.insert(k.clone(), (v.clone(), w));
+        }
+
+        joined
+    }
+
+    /// Returns the entries whose keys are in `keys`, with the same default value as this map.
+    ///
+    /// ```rust
+    /// use easy_collections::{map, set};
+    ///
+    /// let stock = map!{"apples" => 3, "pears" => 1, "plums" => 7};
+    /// let basket = set!{"apples", "plums", "kiwis"};
+    /// assert_eq!(stock.semi_join(&basket), map!{"apples" => 3, "plums" => 7});
+    /// assert_eq!(stock.anti_join(&basket), map!{"pears" => 1});
+    /// assert_eq!(stock.semi_join(vec!["pears"]), map!{"pears" => 1});
+    /// ```
+    pub fn semi_join(&self, keys: impl SetOperand<K>) -> EasyMap<K, V>
+    where
+        V: Clone,
+    {
+        let keys = keys.into_set();
+        self.filtered(|k| keys.has(k))
+    }
+
+    /// Returns the entries whose keys aren't in `keys`, with the same default value as this map.
+    pub fn anti_join(&self, keys: impl SetOperand<K>) -> EasyMap<K, V>
+    where
+        V: Clone,
+    {
+        let keys = keys.into_set();
+        self.filtered(|k| !keys.has(k))
+    }
+
+    /// Returns the entries whose keys match `pred`, with the same default value as this map.
+    fn filtered(&self, pred: impl Fn(&K) -> bool) -> EasyMap<K, V>
+    where
+        V: Clone,
+    {
+        let mut map = self.new_like();
+        for (k, v) in &self.inner {
+            if pred(k) {
+                map.inner.insert(k.clone(), v.clone());
+            }
+        }
+
+        map
+    }
+}
+
+impl<K: Eq + Hash + Ord, V> EasyMap<K, V> {
+    /// Consumes the map, returning its entries sorted by key.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let map = map!{"b" => 1, "c" => 3, "a" => 2};
+    /// assert_eq!(map.into_sorted_vec(), vec![("a", 2), ("b", 1), ("c", 3)]);
+    /// ```
+    pub fn into_sorted_vec(self) -> Vec<(K, V)> {
+        let mut vec = self.inner.into_iter().collect::<Vec<_>>();
+        vec.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        vec
+    }
+
+    /// Consumes the map, returning its entries sorted by value. Entries with equal values are sorted by key.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let map = map!{"b" => 1, "c" => 3, "a" => 1};
+    /// assert_eq!(map.sorted_by_value(), vec![("a", 1), ("b", 1), ("c", 3)]);
+    /// ```
+    pub fn sorted_by_value(self) -> Vec<(K, V)>
+    where
+        V: Ord,
+    {
+        let mut vec = self.inner.into_iter().collect::<Vec<_>>();
+        vec.sort_unstable_by(|(ka, va), (kb, vb)| va.cmp(vb).then_with(|| ka.cmp(kb)));
+        vec
+    }
+}
+
+impl<K: Eq + Hash, V: AddAssign + SubAssign + From<bool>> EasyMap<K, V> {
+    /// Increments the value at `k` by one, starting from the default value if it doesn't exist. One is
+    /// `V::from(true)`, so this works for every integer and float type.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let mut map: EasyMap<_, u32> = EasyMap::new();
+    /// for word in "the cat and the hat".split(' ') {
+    ///     map.incr(word);
+    /// }
+    /// assert_eq!(map["the"], 2);
+    /// assert_eq!(map["cat"], 1);
+    /// assert_eq!(map["dog"], 0);
+    /// ```
+    pub fn incr(&mut self, k: K) {
+        self.incr_by(k, V::from(true));
+    }
+
+    /// Increments the value at `k` by `n`, starting from the default value if it doesn't exist.
+    pub fn incr_by(&mut self, k: K, n: V) {
+        self.apply(k, |v| *v += n);
+    }
+
+    /// Decrements the value at `k` by one, starting from the default value if it doesn't exist.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{10; "foo" => 1};
+    /// map.decr("foo");
+    /// map.decr("bar");
+    /// assert_eq!(map["foo"], 0_i32);
+    /// assert_eq!(map["bar"], 9);
+    /// ```
+    pub fn decr(&mut self, k: K) {
+        self.decr_by(k, V::from(true));
+    }
+
+    /// Decrements the value at `k` by `n`, starting from the default value if it doesn't exist.
+    pub fn decr_by(&mut self, k: K, n: V) {
+        self.apply(k, |v| *v -= n);
+    }
+}
+
+impl<K: Eq + Hash, T: Clone> EasyMap<K, Vec<T>> {
+    /// Pushes `item` onto the `Vec` at `k`, creating it from the default value first if it doesn't exist.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let mut map = EasyMap::new();
+    /// for word in "apple avocado banana".split(' ') {
+    ///     map.push(word.chars().next().unwrap(), word);
+    /// }
+    /// assert_eq!(map['a'], vec!["apple", "avocado"]);
+    /// assert_eq!(map['b'], vec!["banana"]);
+    /// assert!(map['c'].is_empty());
+    /// ```
+    pub fn push(&mut self, k: K, item: T) {
+        self.apply(k, |v| v.push(item));
+    }
+
+    /// Creates a map from an iterator, where each item is pushed onto the `Vec` for its key from `key_fn`.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let words = vec!["apple", "banana", "avocado"];
+    /// let by_initial = EasyMap::index_all_by(words, |w| w.chars().next().unwrap());
+    /// assert_eq!(by_initial['a'], vec!["apple", "avocado"]);
+    /// assert_eq!(by_initial['b'], vec!["banana"]);
+    /// assert!(by_initial['c'].is_empty());
+    /// ```
+    pub fn index_all_by<I, F>(iter: I, mut key_fn: F) -> EasyMap<K, Vec<T>>
+    where
+        I: IntoIterator<Item = T>,
+        F: FnMut(&T) -> K,
+    {
+        let mut map = EasyMap::new();
+        for item in iter {
+            map.push(key_fn(&item), item);
+        }
+
+        map
+    }
+}
+
+impl<K: Eq + Hash, T: Eq + Hash + Clone> EasyMap<K, EasySet<T>> {
+    /// Inserts `item` into the `EasySet` at `k`, creating it from the default value first if it doesn't exist.
+    /// Returns whether the item was newly inserted, the same as `HashSet::insert`.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasyMap};
+    ///
+    /// let mut map = EasyMap::new();
+    /// assert!(map.insert_into("foo", 1));
+    /// assert!(!map.insert_into("foo", 1));
+    /// assert_eq!(map["foo"], set!{1});
+    /// ```
+    pub fn insert_into(&mut self, k: K, item: T) -> bool {
+        let mut inserted = false;
+        self.apply(k, |set| inserted = set.insert(item));
+        inserted
+    }
+}
+
+impl<K1: Eq + Hash + Clone, K2: Eq + Hash + Clone, V> EasyMap<K1, EasyMap<K2, V>> {
+    /// Consumes the nested maps, collapsing them into a single map keyed by `(outer, inner)` tuples. This is the
+    /// reverse of `EasyMap::nest_by`. The new map's default value is taken from the outer map's default inner map, and
+    /// the default values of the inner maps themselves are discarded.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let nested = map!{"a" => map!{1 => 'x'}, "b" => map!{1 => 'y', 2 => 'z'}};
+    /// let flat = nested.flatten();
+    /// assert_eq!(flat, map!{("a", 1) => 'x', ("b", 1) => 'y', ("b", 2) => 'z'});
+    /// ```
+    pub fn flatten(self) -> EasyMap<(K1, K2), V> {
+        let mut flat = EasyMap {
+            inner: HashMap::new(),
+            default: self.default.default,
+            fill: self.default.fill.clone(),
+        };
+        for (outer, inner) in self.inner {
+            for (k, v) in inner.inner {
+                flat.insert((outer.clone(), k), v);
+            }
+        }
+
+        flat
+    }
+}
+
+impl<K: Eq + Hash + Clone> EasyMap<K, bool> {
+    /// Returns the keys which are set to `true`.
+    ///
+    /// ```rust
+    /// use easy_collections::{map, set};
+    ///
+    /// let map = map!{"a" => true, "b" => false, "c" => true};
+    /// assert_eq!(map.true_keys(), set!{"a", "c"});
+    /// ```
+    pub fn true_keys(&self) -> EasySet<K> {
+        self.inner
+            .iter()
+            .filter(|(_, v)| **v)
+            .map(|(k, _)| k.clone())
+            .collect()
+    }
+}
+
+impl<K: Eq + Hash> EasyMap<K, usize> {
+    /// Returns an iterator which yields each key as many times as its count, like Python's `Counter.elements`. The
+    /// order of the keys is arbitrary.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let counts = map!{'a' => 2, 'b' => 0, 'c' => 1};
+    /// let mut elements = counts.elements().collect::<Vec<_>>();
+    /// elements.sort();
+    /// assert_eq!(elements, vec![&'a', &'a', &'c']);
+    /// ```
+    pub fn elements(&self) -> impl Iterator<Item = &K> {
+        self.inner
+            .iter()
+            .flat_map(|(k, n)| std::iter::repeat_n(k, *n))
+    }
+}
+
+impl<V> EasyMap<usize, V> {
+    /// Moves the entries of the map into a `Vec` of length `len`, where each index holds the value for that key. Gaps
+    /// are filled with the default value. Entries whose keys are out of bounds don't fit, so they're left in the map
+    /// rather than being dropped; the map is empty afterwards if everything fit.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{0; 1 => 10, 3 => 30, 9 => 90};
+    /// assert_eq!(map.densify(5), vec![0, 10, 0, 30, 0]);
+    /// assert_eq!(map, map!{0; 9 => 90});
+    /// ```
+    pub fn densify(&mut self, len: usize) -> Vec<V> {
+        let mut vec = (0..len).map(|_| self.fill_default()).collect::<Vec<_>>();
+        for (i, slot) in vec.iter_mut().enumerate() {
+            if let Some(v) = self.inner.remove(&i) {
+                *slot = v;
+            }
+        }
+
+        vec
+    }
+}
+
+impl<V: Default> EasyMap<usize, V> {
+    /// Creates a map from a `Vec`, where each value is keyed by its index. This is the reverse of `EasyMap::densify`.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let map = EasyMap::from_dense(vec!['a', 'b']);
+    /// assert_eq!(map[0], 'a');
+    /// assert_eq!(map[1], 'b');
+    /// assert_eq!(map[2], char::default());
+    /// ```
+    pub fn from_dense(vec: Vec<V>) -> EasyMap<usize, V> {
+        vec.into_iter().enumerate().collect()
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq> EasyMap<K, V> {
+    /// Compares two maps by how they behave, rather than by what they contain. Entries which are equal to the default
+    /// value are treated as if they weren't in the map, since reading them gives the same result either way.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut a = map!{0; "a" => 1};
+    /// let b = map!{0; "a" => 1};
+    /// a["b"] += 0; // this inserts a default value for "b"
+    /// assert_ne!(a, b);
+    /// assert!(a.semantically_eq(&b));
+    /// ```
+    pub fn semantically_eq(&self, other: &EasyMap<K, V>) -> bool {
+        self.default == other.default
+            && self.inner.iter().all(|(k, v)| v == other.get_or_default(k))
+            && other.inner.iter().all(|(k, v)| v == self.get_or_default(k))
+    }
+
+    /// Removes every entry which is equal to the default value, such as those created by `IndexMut`.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut map = map!{0; "a" => 1, "b" => 0};
+    /// map["c"] += 0;
+    /// map.normalize();
+    /// assert_eq!(map, map!{0; "a" => 1});
+    /// ```
+    pub fn normalize(&mut self) {
+        let default = &self.default;
+        self.inner.retain(|_, v| v != default);
+    }
+}
+
+impl<K: Eq + Hash, V: Clone + Eq + Hash> EasyMap<K, V> {
+    /// Counts how many keys have each value. The default value is only counted for keys which are in the map.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let map = map!{"a" => "done", "b" => "todo", "c" => "done"};
+    /// let counts = map.count_values();
+    /// assert_eq!(counts["done"], 2);
+    /// assert_eq!(counts["todo"], 1);
+    /// assert_eq!(counts["nope"], 0);
+    /// ```
+    pub fn count_values(&self) -> EasyMap<V, usize> {
+        let mut counts = EasyMap::new();
+        for v in self.inner.values() {
+            counts.incr(v.clone());
+        }
+
+        counts
+    }
+}
+
+impl<K: Eq + Hash, T: ToOwned + ?Sized> EasyMap<K, Cow<'static, T>> {
+    /// Create a new `EasyMap` whose default value is borrowed from `default`. Reading keys which aren't in the map
+    /// returns the borrowed value, and writing to them only copies the reference, so a large default isn't cloned
+    /// until an entry is actually changed with `Cow::to_mut`.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    /// use std::borrow::Cow;
+    ///
+    /// static EMPTY_ROW: [u8; 4096] = [0; 4096];
+    ///
+    /// let mut rows: EasyMap<usize, Cow<[u8]>> = EasyMap::new_with_default_ref(&EMPTY_ROW);
+    /// assert_eq!(rows[7].len(), 4096);
+    /// rows[1].to_mut()[0] = 1;
+    /// assert!(matches!(rows[1], Cow::Owned(_)));
+    /// assert!(matches!(rows[7], Cow::Borrowed(_)));
+    /// ```
+    pub fn new_with_default_ref(default: &'static T) -> EasyMap<K, Cow<'static, T>> {
+        EasyMap::new_with_default(Cow::Borrowed(default))
+    }
+}
+
+impl EasyMap<String, String> {
+    /// Creates a map from `key=value` command-line arguments, such as those from `std::env::args()`.
+    ///
+    /// The first argument (the program name) is skipped, and leading dashes are trimmed from the keys. Arguments
+    /// without an `=` are inserted with an empty value, so they can be used as flags.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let args = vec!["my-tool", "--port=8080", "host=localhost", "--verbose"];
+    /// let args = EasyMap::from_args(args);
+    /// assert_eq!(args["port".to_string()], "8080");
+    /// assert_eq!(args["host".to_string()], "localhost");
+    /// assert!(args.contains_key("verbose"));
+    /// assert!(!args.contains_key("my-tool"));
+    /// ```
+    pub fn from_args<I>(args: I) -> EasyMap<String, String>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let args = args.into_iter().skip(1);
+        EasyMap::from_kv_pairs(
+            args.map(|a| a.as_ref().trim_start_matches('-').to_string()),
+            '=',
+        )
+    }
+
+    /// Creates a map from a string of delimited key-value pairs, such as `"a=1;b=2"`. Empty pairs are skipped, and
+    /// each pair is split the same as `EasyMap::from_kv_pairs`.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let map = EasyMap::from_delimited("a=1;b=2;c=3", ';', '=');
+    /// assert_eq!(map["a".to_string()], "1");
+    /// assert_eq!(map["c".to_string()], "3");
+    /// ```
+    pub fn from_delimited(s: &str, pair_sep: char, kv_sep: char) -> EasyMap<String, String> {
+        EasyMap::from_kv_pairs(s.split(pair_sep).filter(|pair| !pair.is_empty()), kv_sep)
+    }
+
+    /// Creates a map from strings of key-value pairs, splitting each one at the first `sep`. Strings without `sep`
+    /// are inserted with an empty value.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let map = EasyMap::from_kv_pairs(vec!["a:1", "b:2:3", "c"], ':');
+    /// assert_eq!(map["a".to_string()], "1");
+    /// assert_eq!(map["b".to_string()], "2:3");
+    /// assert_eq!(map["c".to_string()], "");
+    /// ```
+    pub fn from_kv_pairs<I>(iter: I, sep: char) -> EasyMap<String, String>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        iter.into_iter()
+            .map(|s| {
+                let s = s.as_ref();
+                match s.find(sep) {
+                    Some(i) => (s[..i].to_string(), s[i + sep.len_utf8()..].to_string()),
+                    None => (s.to_string(), String::new()),
+                }
+            })
+            .collect()
+    }
+}
+
+impl<V: Default + FromStr> EasyMap<String, V> {
+    /// The same as `EasyMap::from_delimited`, but also parses each value, returning the first error if any fail to
+    /// parse.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let map = EasyMap::<_, u32>::parse_delimited("a=1&b=2", '&', '=').unwrap();
+    /// assert_eq!(map["a".to_string()], 1);
+    /// assert_eq!(map["z".to_string()], 0);
+    ///
+    /// assert!(EasyMap::<_, u32>::parse_delimited("a=1&b=two", '&', '=').is_err());
+    /// ```
+    pub fn parse_delimited(
+        s: &str,
+        pair_sep: char,
+        kv_sep: char,
+    ) -> Result<EasyMap<String, V>, V::Err> {
+        let mut map = EasyMap::new();
+        for (k, v) in EasyMap::from_delimited(s, pair_sep, kv_sep) {
+            map.insert(k, v.parse()?);
+        }
+
+        Ok(map)
+    }
+}
+
+/// Parses a string of `key=value` pairs separated by either `;` or `&` (as in HTTP query strings).
+///
+/// ```rust
+/// use easy_collections::EasyMap;
+///
+/// let map: EasyMap<String, String> = "a=1&b=2;c=3".parse().unwrap();
+/// assert_eq!(map.len(), 3);
+/// ```
+impl FromStr for EasyMap<String, String> {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pairs = s.split(&[';', '&'][..]);
+        Ok(EasyMap::from_kv_pairs(
+            pairs.filter(|pair| !pair.is_empty()),
+            '=',
+        ))
+    }
+}
+
+/// Reshapes `records` into a table, with a row for each key from `row_key` and a column for each key from `col_key`.
+/// Reading a missing row or cell returns `V::default()`, and if several records land in the same cell, the last one
+/// is kept.
+///
+/// ```rust
+/// use easy_collections::pivot;
+///
+/// let sales = vec![("mon", "apples", 3), ("mon", "pears", 1), ("tue", "apples", 5)];
+/// let table = pivot(sales, |s| s.0, |s| s.1, |s| s.2);
+/// assert_eq!(table["mon"]["apples"], 3);
+/// assert_eq!(table["tue"]["apples"], 5);
+/// assert_eq!(table["tue"]["pears"], 0);
+/// assert_eq!(table["wed"]["apples"], 0);
+/// ```
+pub fn pivot<T, R, C, V>(
+    records: impl IntoIterator<Item = T>,
+    mut row_key: impl FnMut(&T) -> R,
+    mut col_key: impl FnMut(&T) -> C,
+    mut value: impl FnMut(T) -> V,
+) -> EasyMap<R, EasyMap<C, V>>
+where
+    R: Eq + Hash,
+    C: Eq + Hash,
+    V: Default,
+{
+    let mut table: EasyMap<R, EasyMap<C, V>> = EasyMap::new();
+    for record in records {
+        let (row, col) = (row_key(&record), col_key(&record));
+        table[row].insert(col, value(record));
+    }
+
+    table
+}
+
+impl<K: Eq + Hash, V: Default> Default for EasyMap<K, V> {
+    fn default() -> Self {
+        EasyMap::new()
+    }
+}
+
+impl<K: Eq + Hash, V: Default> From<Vec<(K, V)>> for EasyMap<K, V> {
+    fn from(v: Vec<(K, V)>) -> Self {
+        v.into_iter().collect()
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone + Default> From<&[(K, V)]> for EasyMap<K, V> {
+    fn from(v: &[(K, V)]) -> Self {
+        v.iter().cloned().collect()
+    }
+}
+
+impl<K: Eq + Hash, V: Default, const N: usize> From<[(K, V); N]> for EasyMap<K, V> {
+    fn from(v: [(K, V); N]) -> Self {
+        IntoIterator::into_iter(v).collect()
+    }
+}
+
+impl<K: Eq + Hash, V: Default> From<HashMap<K, V>> for EasyMap<K, V> {
+    fn from(inner: HashMap<K, V>) -> Self {
+        EasyMap {
+            inner,
+            ..EasyMap::new()
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> From<EasyMap<K, V>> for HashMap<K, V> {
+    fn from(easy: EasyMap<K, V>) -> Self {
+        easy.inner
+    }
+}
+
+/// Creates an indicator map, where each member of the set is `true` and everything else is `false`.
+///
+/// ```rust
+/// use easy_collections::{set, EasyMap};
+///
+/// let map = EasyMap::from(set!{'a', 'b'});
+/// assert!(map['a']);
+/// assert!(!map['z']);
+/// ```
+impl<K: Eq + Hash> From<EasySet<K>> for EasyMap<K, bool> {
+    fn from(set: EasySet<K>) -> Self {
+        set.into_iter().map(|k| (k, true)).collect()
+    }
+}
+
+impl<K: Eq + Hash, V: Default> FromIterator<(K, V)> for EasyMap<K, V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut set = EasyMap::new();
+        for (k, v) in iter {
+            set.insert(k, v);
+        }
+
+        set
+    }
+}
+
+impl<K: Eq + Hash, V> IntoIterator for EasyMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::collections::hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<K: Eq + Hash, V> Deref for EasyMap<K, V> {
+    type Target = HashMap<K, V>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K: Eq + Hash, V> DerefMut for EasyMap<K, V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+/// Compares strings so that runs of digits are ordered by their numeric value, e.g. `"a2" < "a10"`.
+fn natural_cmp(mut a: &str, mut b: &str) -> Ordering {
+    loop {
+        let (a_digits, b_digits) = (
+            a.starts_with(|c: char| c.is_ascii_digit()),
+            b.starts_with(|c: char| c.is_ascii_digit()),
+        );
+        let split = |s: &str, digits: bool| {
+            s.find(|c: char| c.is_ascii_digit() != digits)
+                .unwrap_or(s.len())
+        };
+        let (a_chunk, a_rest) = a.split_at(split(a, a_digits));
+        let (b_chunk, b_rest) = b.split_at(split(b, b_digits));
+
+        let ordering = if a_digits && b_digits {
+            let (a_num, b_num) = (
+                a_chunk.trim_start_matches('0'),
+                b_chunk.trim_start_matches('0'),
+            );
+            a_num
+                .len()
+                .cmp(&b_num.len())
+                .then_with(|| a_num.cmp(b_num))
+                .then_with(|| a_chunk.len().cmp(&b_chunk.len()))
+        } else {
+            a_chunk.cmp(b_chunk)
+        };
+
+        if ordering != Ordering::Equal || (a_rest.is_empty() && b_rest.is_empty()) {
+            return ordering;
+        }
+
+        a = a_rest;
+        b = b_rest;
+    }
+}
+
+/// Writes already formatted entries one per line, sorted by key and with the values aligned.
+fn fmt_pretty(f: &mut fmt::Formatter<'_>, mut entries: Vec<(String, String)>) -> fmt::Result {
+    entries.sort_by(|(a, _), (b, _)| natural_cmp(a, b));
+    let width = entries
+        .iter()
+        .map(|(k, _)| k.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    writeln!(f, "{{")?;
+    for (k, v) in entries {
+        let k = format!("{}:", k);
+        writeln!(f, "    {:width$} {},", k, v, width = width + 1)?;
+    }
+    write!(f, "}}")
+}
+
+/// Formats the map's entries like a Python dict, without exposing the default value. Unlike Python, keys and values
+/// are written with `Display`, so strings aren't quoted: `{foo: 1}` rather than `{"foo": 1}`. Since `Display` can't
+/// tell strings apart from other types, quoting them would also quote numbers; `{:#?}` quotes them with `Debug`.
+///
+/// ```rust
+/// use easy_collections::map;
+///
+/// assert_eq!(map!{"foo" => 1}.to_string(), "{foo: 1}");
+/// assert_eq!(map!{1337; 'a' => 1}.to_string(), "{a: 1}");
+/// ```
+///
+/// The alternate flag (`{:#}`) prints the entries one per line, sorted by key and aligned, which is handy for dumping
+/// large maps while debugging:
+/// ```rust
+/// use easy_collections::map;
+///
+/// let map = map!{"foo" => 1, "x" => 2, "bar" => 3};
+/// assert_eq!(format!("{:#}", map), "{\n    bar: 3,\n    foo: 1,\n    x:   2,\n}");
+/// ```
+impl<K: Eq + Hash + Display, V: Display> Display for EasyMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let entries = self
+                .inner
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()));
+            return fmt_pretty(f, entries.collect());
+        }
+
+        write!(f, "{{")?;
+        for (i, (k, v)) in self.inner.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", k, v)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// The alternate flag (`{:#?}`) prints the entries one per line, sorted by key and aligned, followed by the default.
+///
+/// ```rust
+/// use easy_collections::map;
+///
+/// let map = map!{42; "a" => 1, "bc" => 2};
+/// assert_eq!(format!("{:#?}", map), "{\n    \"a\":  1,\n    \"bc\": 2,\n} (default: 42)");
+/// ```
+impl<K: Eq + Hash + Debug, V: Debug> Debug for EasyMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let entries = self
+                .inner
+                .iter()
+                .map(|(k, v)| (format!("{:?}", k), format!("{:?}", v)));
+            fmt_pretty(f, entries.collect())?;
+            return write!(f, " (default: {:?})", self.default);
+        }
+
+        f.debug_struct("EasyMap")
+            .field("inner", &self.inner)
+            .field("default", &self.default)
+            .finish()
+    }
+}
+
+impl<K: Eq + Hash, V> Index<K> for EasyMap<K, V> {
+    type Output = V;
+    fn index(&self, key: K) -> &Self::Output {
+        self.get_or_default(&key)
+    }
+}
+
+impl<K: Eq + Hash, V> IndexMut<K> for EasyMap<K, V> {
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        let (default, fill) = (&self.default, &self.fill);
+        self.inner.entry(key).or_insert_with(|| fill.make(default))
+    }
+}
+
+/// Maps can also be indexed by reference, so keys which aren't `Copy` don't need to be moved or cloned to read them.
+/// Writing by reference only clones the key if it isn't in the map yet. Since a reference could be the key type
+/// itself, the key type needs to be known before indexing by reference.
+///
+/// ```rust
+/// use easy_collections::EasyMap;
+///
+/// let mut map: EasyMap<String, usize> = EasyMap::new();
+/// let key = String::from("hello");
+/// map[&key] += 1;
+/// map[&key] += 1;
+/// assert_eq!(map[&key], 2);
+/// assert_eq!(map[key], 2);
+/// ```
+impl<K: Eq + Hash, V> Index<&K> for EasyMap<K, V> {
+    type Output = V;
+    fn index(&self, key: &K) -> &Self::Output {
+        self.get_or_default(key)
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> IndexMut<&K> for EasyMap<K, V> {
+    fn index_mut(&mut self, key: &K) -> &mut Self::Output {
+        if !self.inner.contains_key(key) {
+            let v = self.fill_default();
+            self.inner.insert(key.clone(), v);
+        }
+
+        self.inner.get_mut(key).unwrap()
+    }
+}
+
+/// Maps with `Cow<'static, str>` keys can be indexed by string literals without allocating, and by owned `String`s
+/// too. In the `map!` macro, start with `into;` to convert the keys.
+///
+/// ```rust
+/// use easy_collections::{map, EasyMap};
+/// use std::borrow::Cow;
+///
+/// let mut map: EasyMap<Cow<str>, usize> = map!{into; "literal" => 1, String::from("owned") => 2};
+/// map["another"] += 3;
+/// map[format!("built at {}", "runtime")] = 4;
+/// assert_eq!(map["literal"], 1);
+/// assert_eq!(map[String::from("owned")], 2);
+/// assert_eq!(map["another"], 3);
+/// assert_eq!(map["built at runtime"], 4);
+/// assert!(matches!(map.keys().find(|k| *k == "another"), Some(Cow::Borrowed(_))));
+/// ```
+impl<V> Index<&'static str> for EasyMap<Cow<'static, str>, V> {
+    type Output = V;
+    fn index(&self, key: &'static str) -> &Self::Output {
+        self.inner.get(key).unwrap_or(&self.default)
+    }
+}
+
+impl<V> IndexMut<&'static str> for EasyMap<Cow<'static, str>, V> {
+    fn index_mut(&mut self, key: &'static str) -> &mut Self::Output {
+        let (default, fill) = (&self.default, &self.fill);
+        self.inner
+            .entry(Cow::Borrowed(key))
+            .or_insert_with(|| fill.make(default))
+    }
+}
+
+impl<V> Index<String> for EasyMap<Cow<'static, str>, V> {
+    type Output = V;
+    fn index(&self, key: String) -> &Self::Output {
+        self.inner.get(key.as_str()).unwrap_or(&self.default)
+    }
+}
+
+impl<V> IndexMut<String> for EasyMap<Cow<'static, str>, V> {
+    fn index_mut(&mut self, key: String) -> &mut Self::Output {
+        let (default, fill) = (&self.default, &self.fill);
+        self.inner
+            .entry(Cow::Owned(key))
+            .or_insert_with(|| fill.make(default))
+    }
+}
+
+/// Merges two maps, the same as Python's `dict` union. Values from the right hand side win, and the default value of
+/// the left hand side is kept.
+///
+/// The right hand side can be anything that implements `Into<EasyMap<K, V>>`, for example:
+/// ```rust
+/// use easy_collections::map;
+/// use std::collections::HashMap;
+///
+/// let mut map = map!{-1; "a" => 1};
+/// map |= vec![("b", 2)];
+/// map |= [("c", 3)];
+/// map |= &[("e", 5)][..];
+/// map |= HashMap::from([("a", 10)]);
+/// assert_eq!(map, map!{-1; "a" => 10, "b" => 2, "c" => 3, "e" => 5});
+/// assert_eq!(&map | &map!{"d" => 4}, map!{-1; "a" => 10, "b" => 2, "c" => 3, "d" => 4, "e" => 5});
+/// ```
+impl<K: Eq + Hash, V, T: Into<EasyMap<K, V>>> BitOrAssign<T> for EasyMap<K, V> {
+    fn bitor_assign(&mut self, rhs: T) {
+        self.update(rhs.into());
     }
 }
-
-impl<K: Eq + Hash, V: Clone> Deref for EasyMap<K, V> {
-    type Target = HashMap<K, V>;
-    fn deref(&self) -> &Self::Target {
-        &self.inner
+impl<K: Eq + Hash + Clone, V: Clone> BitOrAssign<&EasyMap<K, V>> for EasyMap<K, V> {
+    fn bitor_assign(&mut self, rhs: &EasyMap<K, V>) {
+        self.inner
+            .extend(rhs.iter().map(|(k, v)| (k.clone(), v.clone())));
     }
 }
-
-impl<K: Eq + Hash, V: Clone> DerefMut for EasyMap<K, V> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.inner
+impl<K: Eq + Hash, V, T: Into<EasyMap<K, V>>> BitOr<T> for EasyMap<K, V> {
+    type Output = Self;
+    fn bitor(mut self, rhs: T) -> Self::Output {
+        self |= rhs;
+        self
     }
 }
-
-impl<K: Eq + Hash, V: Clone> Index<K> for EasyMap<K, V> {
-    type Output = V;
-    fn index(&self, key: K) -> &Self::Output {
-        self.inner.get(&key).unwrap_or(&self.default)
+impl<K: Eq + Hash + Clone, V: Clone> BitOr<&EasyMap<K, V>> for EasyMap<K, V> {
+    type Output = Self;
+    fn bitor(mut self, rhs: &EasyMap<K, V>) -> Self::Output {
+        self |= rhs;
+        self
     }
 }
-
-impl<K: Eq + Hash, V: Clone> IndexMut<K> for EasyMap<K, V> {
-    fn index_mut(&mut self, key: K) -> &mut Self::Output {
-        self.inner.entry(key).or_insert(self.default.clone())
+impl<K: Eq + Hash + Clone, V: Clone, T: Into<EasyMap<K, V>>> BitOr<T> for &EasyMap<K, V> {
+    type Output = EasyMap<K, V>;
+    fn bitor(self, rhs: T) -> Self::Output {
+        self.clone() | rhs
+    }
+}
+impl<K: Eq + Hash + Clone, V: Clone> BitOr<&EasyMap<K, V>> for &EasyMap<K, V> {
+    type Output = EasyMap<K, V>;
+    fn bitor(self, rhs: &EasyMap<K, V>) -> Self::Output {
+        self.clone() | rhs
     }
 }
 
@@ -329,4 +1865,795 @@ mod test {
         assert_eq!(map["foo"], 420);
         assert_eq!(map["bar"], 10);
     }
+
+    #[test]
+    fn pop() {
+        let mut map = map! {1; "foo" => 42, "bar" => 10};
+        assert_eq!(map.pop("foo"), Some(42));
+        assert_eq!(map.pop("foo"), None);
+        assert_eq!(map.pop_or("bar", 0), 10);
+        assert_eq!(map.pop_or("bar", 0), 0);
+        assert!(map.is_empty());
+        assert_eq!(map["foo"], 1);
+    }
+
+    #[test]
+    fn pop_entry() {
+        let mut map = map! {"foo" => 1, "bar" => 2};
+        let mut entries = vec![];
+        while let Some(entry) = map.pop_entry() {
+            entries.push(entry);
+        }
+
+        // the entries could be in any order
+        entries.sort();
+        assert_eq!(entries, &[("bar", 2), ("foo", 1)]);
+        assert_eq!(map.pop_entry(), None);
+    }
+
+    #[test]
+    fn drain() {
+        let mut map = map! {1; 'a' => 10, 'b' => 20};
+        let capacity = map.capacity();
+
+        let mut values = map.drain().collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values, &[('a', 10), ('b', 20)]);
+
+        assert!(map.is_empty());
+        assert_eq!(map.capacity(), capacity);
+        assert_eq!(map['a'], 1);
+    }
+
+    #[test]
+    fn apply() {
+        let mut map = map! {1; 'a' => 10};
+
+        // test existing key
+        assert_eq!(*map.apply('a', |v| *v *= 2), 20);
+        assert_eq!(map['a'], 20);
+
+        // test non-existent key
+        assert_eq!(*map.apply('b', |v| *v += 1), 2);
+        assert_eq!(map['b'], 2);
+        assert_eq!(map['c'], 1);
+    }
+
+    #[test]
+    fn incr_decr() {
+        let mut map: EasyMap<char, i64> = map! {};
+        map.incr('a');
+        map.incr('a');
+        map.incr_by('b', 10);
+        map.decr('c');
+        map.decr_by('b', 3);
+
+        assert_eq!(map['a'], 2);
+        assert_eq!(map['b'], 7);
+        assert_eq!(map['c'], -1);
+
+        // floats work too
+        let mut map = map! {0.5; 'a' => 1.0};
+        map.incr('a');
+        map.incr('b');
+        assert_eq!(map['a'], 2.0);
+        assert_eq!(map['b'], 1.5);
+
+        // i8 doesn't convert from u8
+        let mut map: EasyMap<char, i8> = map! {};
+        map.decr('a');
+        map.incr('b');
+        assert_eq!(map['a'], -1);
+        assert_eq!(map['b'], 1);
+    }
+
+    #[test]
+    fn push() {
+        let mut map = map! {vec![0]; 'a' => vec![]};
+        map.push('a', 1);
+        map.push('a', 2);
+        map.push('b', 3);
+
+        assert_eq!(map['a'], &[1, 2]);
+        assert_eq!(map['b'], &[0, 3]);
+        assert_eq!(map['c'], &[0]);
+    }
+
+    #[test]
+    fn insert_into() {
+        let mut map: EasyMap<char, EasySet<usize>> = map! {};
+        assert!(map.insert_into('a', 1));
+        assert!(map.insert_into('a', 2));
+        assert!(!map.insert_into('a', 1));
+        assert!(map.insert_into('b', 3));
+
+        assert_eq!(map['a'], crate::set! {1, 2});
+        assert_eq!(map['b'], crate::set! {3});
+        assert_eq!(map['c'], crate::set! {});
+    }
+
+    #[test]
+    fn get_many_mut() {
+        let mut map = map! {'a' => 1, 'b' => 2, 'c' => 3};
+        let [a, c] = map.get_many_mut([&'a', &'c']).unwrap();
+        std::mem::swap(a, c);
+        assert_eq!(map, map! {'a' => 3, 'b' => 2, 'c' => 1});
+
+        // missing keys
+        assert!(map.get_many_mut([&'a', &'d']).is_none());
+        // duplicate keys
+        assert!(map.get_many_mut([&'b', &'a', &'b']).is_none());
+        // no keys
+        assert_eq!(map.get_many_mut([]), Some([]));
+    }
+
+    #[test]
+    fn from_args() {
+        let args = ["program", "-a=1", "--b=2=3", "c", "--d="];
+        let map = EasyMap::from_args(args.iter().map(|s| s.to_string()));
+        assert_eq!(
+            map,
+            EasyMap::from(vec![
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "2=3".to_string()),
+                ("c".to_string(), "".to_string()),
+                ("d".to_string(), "".to_string()),
+            ])
+        );
+
+        // only the program name
+        assert!(EasyMap::from_args(vec!["a=1"]).is_empty());
+    }
+
+    #[test]
+    fn from_kv_pairs() {
+        let map = EasyMap::from_kv_pairs(vec!["foo→bar", "→baz", "qux→"], '→');
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["foo".to_string()], "bar");
+        assert_eq!(map["".to_string()], "baz");
+        assert_eq!(map["qux".to_string()], "");
+    }
+
+    #[test]
+    fn from_delimited() {
+        let map = EasyMap::from_delimited(";a=1;;b=2=3;c", ';', '=');
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["a".to_string()], "1");
+        assert_eq!(map["b".to_string()], "2=3");
+        assert_eq!(map["c".to_string()], "");
+
+        let map = EasyMap::<_, f64>::parse_delimited("x:1.5,y:-2", ',', ':').unwrap();
+        assert_eq!(map["x".to_string()], 1.5);
+        assert_eq!(map["y".to_string()], -2.0);
+        assert!(EasyMap::<_, f64>::parse_delimited("x:1.5,y", ',', ':').is_err());
+
+        let map = "a=1&b=2;c=3&".parse::<EasyMap<_, _>>().unwrap();
+        assert_eq!(map, EasyMap::from_delimited("a=1;b=2;c=3", ';', '='));
+    }
+
+    #[test]
+    fn display() {
+        let map: EasyMap<u8, u8> = map! {42};
+        assert_eq!(format!("{}", map), "{}");
+
+        let map = map! {"foo" => 1.5};
+        assert_eq!(format!("{}", map), "{foo: 1.5}");
+
+        // the entries could be in any order
+        let s = format!("{}", map! {'a' => "b", 'c' => "d"});
+        assert!(s == "{a: b, c: d}" || s == "{c: d, a: b}");
+    }
+
+    #[test]
+    fn display_alternate() {
+        let map: EasyMap<u8, u8> = map! {42};
+        assert_eq!(format!("{:#}", map), "{\n}");
+
+        let map = map! {10 => 'a', 9 => 'b', 100 => 'c'};
+        assert_eq!(
+            format!("{:#}", map),
+            "{\n    9:   b,\n    10:  a,\n    100: c,\n}"
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let map = map! {1; 'a' => 2};
+        assert_eq!(
+            format!("{:?}", map),
+            "EasyMap { inner: {'a': 2}, default: 1 }"
+        );
+
+        let map = map! {"item10" => (), "item2" => (), "Item" => ()};
+        assert_eq!(
+            format!("{:#?}", map),
+            "{\n    \"Item\":   (),\n    \"item2\":  (),\n    \"item10\": (),\n} (default: ())"
+        );
+    }
+
+    #[test]
+    fn natural_cmp() {
+        use super::natural_cmp;
+
+        assert_eq!(natural_cmp("a2", "a10"), Ordering::Less);
+        assert_eq!(natural_cmp("10", "9"), Ordering::Greater);
+        assert_eq!(natural_cmp("007", "7"), Ordering::Greater);
+        assert_eq!(natural_cmp("abc", "abd"), Ordering::Less);
+        assert_eq!(natural_cmp("a1b", "a1"), Ordering::Greater);
+        assert_eq!(natural_cmp("", ""), Ordering::Equal);
+        assert_eq!(natural_cmp("x1y2", "x1y2"), Ordering::Equal);
+    }
+
+    #[test]
+    fn partition() {
+        let map = map! {0; 'a' => 1, 'b' => 2, 'c' => 3};
+        let (a, b) = map.partition(|k, v| *k == 'a' || *v == 3);
+        assert_eq!(a, map! {0; 'a' => 1, 'c' => 3});
+        assert_eq!(b, map! {0; 'b' => 2});
+
+        let (a, b) = map! {7; 'a' => 1}.partition(|_, _| true);
+        assert_eq!(a, map! {7; 'a' => 1});
+        assert_eq!(b, map! {7});
+    }
+
+    #[test]
+    fn split_off_keys() {
+        let mut map = map! {0; 'a' => 1, 'b' => 2, 'c' => 3};
+        let split = map.split_off_keys(vec!['a', 'b', 'b', 'z']);
+        assert_eq!(map, map! {0; 'c' => 3});
+        assert_eq!(split, map! {0; 'a' => 1, 'b' => 2});
+
+        let split = map.split_off_keys(None::<char>);
+        assert_eq!(split, map! {0});
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn default_value() {
+        let mut map: EasyMap<char, u8> = map! {'a' => 1};
+        assert_eq!(*map.default_value(), 0);
+
+        assert_eq!(map.set_default(5), 0);
+        assert_eq!(*map.default_value(), 5);
+        assert_eq!(map['b'], 5);
+        map['c'] += 1;
+        assert_eq!(map['c'], 6);
+
+        let map = map.with_default(10);
+        assert_eq!(map['a'], 1);
+        assert_eq!(map['c'], 6);
+        assert_eq!(map['d'], 10);
+
+        // the `Default` trait is still usable
+        let map: EasyMap<char, u8> = Default::default();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn semantically_eq() {
+        let a = map! {0; 'a' => 0, 'b' => 1};
+        let b = map! {0; 'b' => 1, 'c' => 0};
+        assert!(a.semantically_eq(&b));
+        assert!(b.semantically_eq(&a));
+        assert!(a.semantically_eq(&a));
+
+        // different values
+        assert!(!a.semantically_eq(&map! {0; 'b' => 2}));
+        assert!(!map! {0; 'b' => 2}.semantically_eq(&a));
+
+        // different defaults
+        assert!(!a.semantically_eq(&map! {1; 'b' => 1}));
+        assert!(!map! {1}.semantically_eq(&map! {0; 'a' => 1}));
+    }
+
+    #[test]
+    fn normalize() {
+        let mut map = map! {1; 'a' => 1, 'b' => 2};
+        map['c'] += 0;
+        map['d'] -= 1;
+        map.normalize();
+        assert_eq!(map, map! {1; 'b' => 2, 'd' => 0});
+        assert_eq!(map['a'], 1);
+    }
+
+    #[test]
+    fn densify() {
+        let map = map! {-1; 0 => 5, 2 => 7, 4 => 9};
+        let mut rest = map.clone();
+        assert_eq!(rest.densify(4), vec![5, -1, 7, -1]);
+        // out of bounds entries are kept
+        assert_eq!(rest, map! {-1; 4 => 9});
+        assert!(map.clone().densify(0).is_empty());
+        assert_eq!(map! {'x'}.densify(2), vec!['x', 'x']);
+
+        let mut map = EasyMap::from_dense(vec![1, 2, 3]);
+        assert_eq!(map, map! {0 => 1, 1 => 2, 2 => 3});
+        assert_eq!(map.densify(3), vec![1, 2, 3]);
+        assert!(map.is_empty());
+        assert!(EasyMap::<usize, u8>::from_dense(vec![]).is_empty());
+    }
+
+    #[test]
+    fn into_sorted_vec() {
+        let map = map! {'-'; 3 => 'a', 1 => 'c', 2 => 'b'};
+        assert_eq!(
+            map.clone().into_sorted_vec(),
+            vec![(1, 'c'), (2, 'b'), (3, 'a')]
+        );
+        assert_eq!(map.sorted_by_value(), vec![(3, 'a'), (2, 'b'), (1, 'c')]);
+
+        let map = map! {"z" => 1, "y" => 1, "x" => 0};
+        assert_eq!(map.sorted_by_value(), vec![("x", 0), ("y", 1), ("z", 1)]);
+        assert!(EasyMap::<u8, u8>::new().into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn indicator_maps() {
+        let mut map = EasyMap::from(crate::set! {1, 2, 3});
+        assert_eq!(map.len(), 3);
+        assert!(map[1] && map[2] && map[3]);
+        assert!(!map[4]);
+
+        map[2] = false;
+        map[4] = true;
+        assert_eq!(map.true_keys(), crate::set! {1, 3, 4});
+        assert!(EasyMap::<u8, bool>::new().true_keys().is_empty());
+    }
+
+    #[test]
+    fn count_values() {
+        let mut map = map! {'a' => 1, 'b' => 2, 'c' => 1};
+        map['d'] += 0;
+        let counts = map.count_values();
+        assert_eq!(counts, map! {1 => 2, 2 => 1, 0 => 1});
+        assert_eq!(counts[3], 0);
+        assert!(EasyMap::<u8, u8>::new().count_values().is_empty());
+    }
+
+    #[test]
+    fn index_by() {
+        #[derive(Clone, Debug, Default, PartialEq)]
+        struct User {
+            id: u32,
+            team: &'static str,
+        }
+
+        let users = vec![
+            User { id: 1, team: "red" },
+            User {
+                id: 2,
+                team: "blue",
+            },
+            User { id: 3, team: "red" },
+        ];
+
+        let by_id = EasyMap::index_by(users.clone(), |u| u.id);
+        assert_eq!(by_id.len(), 3);
+        assert_eq!(by_id[2].team, "blue");
+        assert_eq!(by_id[4], User::default());
+
+        let by_team = EasyMap::index_by(users.clone(), |u| u.team);
+        assert_eq!(by_team.len(), 2);
+        assert_eq!(by_team["red"].id, 3);
+
+        let by_team = EasyMap::index_all_by(users, |u| u.team);
+        assert_eq!(
+            by_team["red"].iter().map(|u| u.id).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(by_team["blue"].len(), 1);
+        assert!(by_team["green"].is_empty());
+    }
+
+    #[test]
+    fn nest_by() {
+        let map = map! {-1; "a.x" => 1, "a.y" => 2, "b.x" => 3};
+        let nested = map.nest_by(|k| {
+            let (outer, inner) = k.split_once('.').unwrap();
+            (outer.to_string(), inner.to_string())
+        });
+        assert_eq!(nested.len(), 2);
+        assert_eq!(
+            nested["a".to_string()],
+            map! {-1; "x".to_string() => 1, "y".to_string() => 2}
+        );
+        assert_eq!(nested["b".to_string()]["y".to_string()], -1);
+        assert_eq!(*nested["c".to_string()].default_value(), -1);
+        assert!(nested["c".to_string()].is_empty());
+    }
+
+    #[test]
+    fn flatten() {
+        let map = map! {0; (1, 'a') => 10, (1, 'b') => 20, (2, 'a') => 30};
+        let nested = map.clone().nest_by(|k| k);
+        assert_eq!(nested.len(), 2);
+        assert_eq!(nested.flatten(), map);
+
+        let mut nested = map! {map! {7; 'a' => 1}};
+        nested[1] = map! {'a' => 2};
+        let flat = nested.flatten();
+        assert_eq!(flat, map! {7; (1, 'a') => 2});
+        assert_eq!(flat[(0, 'a')], 7);
+
+        // the inner maps' own defaults are discarded
+        let mut nested = map! {map! {7}};
+        nested[1] = map! {-1; 'a' => 2};
+        assert_eq!(nested[1]['b'], -1);
+        let flat = nested.flatten();
+        assert_eq!(flat[(1, 'b')], 7);
+        assert_eq!(*flat.default_value(), 7);
+    }
+
+    #[test]
+    fn update_and_union() {
+        let mut map = map! {0; 'a' => 1, 'b' => 2};
+        map.update(vec![('b', 3)]);
+        map.update(HashMap::from([('c', 4)]));
+        assert_eq!(map, map! {'a' => 1, 'b' => 3, 'c' => 4});
+        map.update([('x', 8)]);
+        map.update(&[('x', 9)][..]);
+        assert_eq!(map.pop('x'), Some(9));
+
+        let other = map! {9; 'a' => 5, 'd' => 6};
+        let union = &map | &other;
+        assert_eq!(union, map! {'a' => 5, 'b' => 3, 'c' => 4, 'd' => 6});
+        assert_eq!(union['z'], 0);
+        assert_eq!((other.clone() | &map)['z'], 9);
+        assert_eq!(
+            map.clone() | [('e', 7)].as_ref(),
+            map! {'a' => 1, 'b' => 3, 'c' => 4, 'e' => 7}
+        );
+
+        map |= &other;
+        map |= vec![('b', 0)];
+        map |= &[('c', 4)][..];
+        assert_eq!(map, map! {'a' => 5, 'b' => 0, 'c' => 4, 'd' => 6});
+        assert_eq!(other, map! {9; 'a' => 5, 'd' => 6});
+    }
+
+    #[test]
+    fn hash_map_conversions() {
+        let hash = HashMap::from([("a", 1)]);
+        let map = EasyMap::from(hash.clone());
+        assert_eq!(map["b"], 0);
+        assert_eq!(HashMap::from(map), hash);
+        assert_eq!(
+            EasyMap::from([(1, 'x'), (2, 'y')]),
+            map! {1 => 'x', 2 => 'y'}
+        );
+    }
+
+    #[test]
+    fn nested_macros() {
+        let map = map! {
+            "a" => map! {"x" => map! {1 => 'a'}},
+            "b" => map! {},
+        };
+        assert_eq!(map["a"]["x"][1], 'a');
+        assert_eq!(map["a"]["x"][2], char::default());
+        assert!(map["b"].is_empty());
+        assert!(map["c"]["x"].is_empty());
+        // entries are inserted directly, so the outer default isn't cloned for them
+        assert_eq!(map.len(), 2);
+
+        let map = map! {map!{'?'}; 1 => map!{'?'; 2 => 'b'}};
+        assert_eq!(map[1][2], 'b');
+        assert_eq!(map[1][3], '?');
+        assert_eq!(map[4][2], '?');
+    }
+
+    #[test]
+    fn elements() {
+        let mut counts = EasyMap::new();
+        assert_eq!(counts.elements().count(), 0);
+        counts['x'] = 3;
+        counts['y'] = 0;
+        assert_eq!(
+            counts.elements().collect::<Vec<_>>(),
+            vec![&'x', &'x', &'x']
+        );
+
+        let flat = "mississippi".chars().collect::<crate::EasyBag<_>>();
+        let counts = flat
+            .iter()
+            .map(|(k, n)| (*k, *n))
+            .collect::<EasyMap<_, _>>();
+        let mut elements = counts.elements().copied().collect::<Vec<_>>();
+        elements.sort();
+        assert_eq!(elements.into_iter().collect::<String>(), "iiiimppssss");
+    }
+
+    #[test]
+    fn with_without() {
+        let map = EasyMap::new_with_default(0)
+            .with('a', 1)
+            .with('b', 2)
+            .with('a', 3);
+        assert_eq!(map, map! {0; 'a' => 3, 'b' => 2});
+        assert_eq!(map.clone().without(&'z'), map);
+        let map = map.without(&'a');
+        assert_eq!(map.len(), 1);
+        assert_eq!(map['a'], 0);
+
+        let squares = (1..4).fold(EasyMap::new(), |acc, n| acc.with(n, n * n));
+        assert_eq!(squares, map! {1 => 1, 2 => 4, 3 => 9});
+    }
+
+    #[test]
+    fn cow_keys() {
+        let mut map: EasyMap<Cow<'static, str>, Vec<u8>> = map! {into; "a" => vec![1]};
+        map["a"].push(2);
+        map[String::from("b")].push(3);
+        map["b"].push(4);
+        assert_eq!(map["a"], vec![1, 2]);
+        assert_eq!(map[String::from("b")], vec![3, 4]);
+        assert!(map["c"].is_empty());
+        assert_eq!(map.len(), 2);
+        assert!(matches!(
+            map.get_key_value("a"),
+            Some((Cow::Borrowed(_), _))
+        ));
+        assert!(matches!(map.get_key_value("b"), Some((Cow::Owned(_), _))));
+    }
+
+    #[test]
+    fn index_by_ref() {
+        let mut map = map! {vec![0]; String::from("a") => vec![1]};
+        let keys = vec![String::from("a"), String::from("b")];
+        for key in &keys {
+            map[key].push(2);
+        }
+        assert_eq!(map[&keys[0]], vec![1, 2]);
+        assert_eq!(map[&keys[1]], vec![0, 2]);
+        assert_eq!(map[&String::from("c")], vec![0]);
+        assert_eq!(map.len(), 2);
+
+        let refs = map! {"x" => 1};
+        assert_eq!(refs[&"x"], 1);
+        assert_eq!(refs["x"], 1);
+    }
+
+    #[test]
+    fn modify() {
+        let mut map = map! {10; String::from("a") => 1};
+        let a = String::from("a");
+        let b = String::from("b");
+        assert_eq!(map.modify(&a, |v| std::mem::replace(v, 2)), 1);
+        map.modify(&b, |v| *v += 1);
+        assert_eq!(map, map! {10; a.clone() => 2, b.clone() => 11});
+
+        let mut unchanged = map.clone();
+        unchanged.modify(&String::from("c"), |_| ());
+        assert_eq!(unchanged.len(), 3);
+        assert_eq!(unchanged[String::from("c")], 10);
+    }
+
+    #[test]
+    fn map_into() {
+        let map = map! {7_u8; 'a' => 1, 'b' => 2};
+        let map: EasyMap<char, u32> = map.map_into();
+        assert_eq!(map, map! {7; 'a' => 1, 'b' => 2});
+
+        let map: EasyMap<char, String> = map! {"?"; 'x' => "y"}.map_into();
+        assert_eq!(map['x'], "y");
+        assert_eq!(map['z'], "?");
+    }
+
+    #[test]
+    fn try_map_values() {
+        let map = map! {"x"; 1 => "10", 2 => "20"};
+        let parsed = map.clone().try_map_values(|v| v.parse::<i32>()).unwrap();
+        assert_eq!(parsed, map! {1 => 10, 2 => 20});
+        assert!(map
+            .with(3, "x")
+            .try_map_values(|v| v.parse::<i32>())
+            .is_err());
+
+        let empty = EasyMap::<u8, u8>::new().try_map_values(Err::<u8, u8>);
+        assert_eq!(empty, Ok(EasyMap::new()));
+    }
+
+    #[test]
+    fn try_from_iter() {
+        let ok = EasyMap::try_from_iter(vec![Ok::<_, ()>((1, 'a')), Ok((2, 'b'))]);
+        assert_eq!(ok, Ok(map! {1 => 'a', 2 => 'b'}));
+        let err = EasyMap::<u8, char>::try_from_iter(vec![Ok((1, 'a')), Err(2), Err(3)]);
+        assert_eq!(err, Err(2));
+    }
+
+    #[test]
+    fn try_collect() {
+        let entries = vec![Ok(('a', 1)), Err(2), Ok(('b', 3))];
+        let map = entries
+            .into_iter()
+            .collect::<Result<EasyMap<char, u8>, u8>>();
+        assert_eq!(map, Err(2));
+        let map = vec![Ok::<_, ()>(('a', 1))]
+            .into_iter()
+            .collect::<Result<EasyMap<_, _>, _>>();
+        assert_eq!(map, Ok(map! {'a' => 1}));
+    }
+
+    #[test]
+    fn snapshot() {
+        let mut map = map! {-1; 'a' => 1};
+        let snapshot = map.snapshot();
+        let shared = Arc::clone(&snapshot);
+        map['b'] = 2;
+        map.remove('a');
+        assert_eq!(*snapshot, HashMap::from([('a', 1)]));
+        assert_eq!(shared.get(&'b'), None);
+        assert_eq!(*map.snapshot(), HashMap::from([('b', 2)]));
+    }
+
+    #[test]
+    fn drain_into() {
+        let mut map = map! {1; 'a' => 10, 'b' => 20};
+        let capacity = map.capacity();
+        let mut other = map! {2; 'b' => 0, 'c' => 30};
+        map.drain_into(&mut other);
+        assert!(map.is_empty());
+        assert_eq!(map.capacity(), capacity);
+        assert_eq!(other, map! {2; 'a' => 10, 'b' => 20, 'c' => 30});
+        assert_eq!(other['z'], 2);
+    }
+
+    #[test]
+    fn swap() {
+        let mut map = map! {0; 'a' => 1, 'b' => 2};
+        map.swap(&'a', &'b');
+        assert_eq!(map, map! {0; 'a' => 2, 'b' => 1});
+        map.swap(&'c', &'a');
+        assert_eq!(map, map! {0; 'a' => 0, 'b' => 1, 'c' => 2});
+        map.swap(&'d', &'d');
+        assert_eq!(map['d'], 0);
+        assert_eq!(map.len(), 4);
+    }
+
+    #[test]
+    fn rename_key() {
+        let mut map = map! {0; 'a' => 1, 'b' => 2};
+        assert!(map.rename_key(&'a', 'a'));
+        assert!(!map.rename_key(&'a', 'b'));
+        assert!(map.rename_key(&'a', 'c'));
+        assert!(!map.rename_key(&'z', 'y'));
+        assert_eq!(map, map! {0; 'b' => 2, 'c' => 1});
+
+        assert_eq!(map.replace_key(&'c', 'c'), None);
+        assert_eq!(map.replace_key(&'z', 'c'), None);
+        assert_eq!(map.replace_key(&'c', 'b'), Some(2));
+        assert_eq!(map.replace_key(&'b', 'd'), None);
+        assert_eq!(map, map! {0; 'd' => 1});
+    }
+
+    #[test]
+    fn without_clone() {
+        #[derive(Debug, Default, PartialEq)]
+        struct Handle(Vec<u8>);
+
+        let mut map: EasyMap<char, Handle> = EasyMap::new();
+        map['a'].0.push(1);
+        map[&'b'].0.push(2);
+        map.insert('c', Handle(vec![3]));
+        assert_eq!(map['a'], Handle(vec![1]));
+        assert_eq!(map['b'], Handle(vec![2]));
+        assert_eq!(map['z'], Handle(vec![]));
+        assert_eq!(map.len(), 3);
+
+        let map: EasyMap<char, Handle> = vec![('x', Handle(vec![9]))].into_iter().collect();
+        assert_eq!(map, EasyMap::from(HashMap::from([('x', Handle(vec![9]))])));
+        assert_eq!(HashMap::from(map).len(), 1);
+    }
+
+    #[test]
+    fn factory() {
+        struct Counter(u8);
+
+        let mut map: EasyMap<char, Counter> = EasyMap::new_with_factory(|| Counter(1));
+        map['a'].0 += 1;
+        map[&'b'].0 += 2;
+        map.apply('c', |c| c.0 += 3);
+        map.entry_ref(&'d').or_default().0 += 4;
+        assert_eq!(map['a'].0, 2);
+        assert_eq!(map['b'].0, 3);
+        assert_eq!(map['c'].0, 4);
+        assert_eq!(map['d'].0, 5);
+        assert_eq!(map['z'].0, 1);
+
+        let (mut big, _) = map.partition(|_, c| c.0 > 3);
+        assert_eq!(big.len(), 2);
+        big['y'].0 += 1;
+        assert_eq!(big['y'].0, 2);
+    }
+
+    #[test]
+    fn capturing_factory() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let made = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&made);
+        let mut map: EasyMap<char, usize> =
+            EasyMap::new_with_factory(move || counter.fetch_add(1, Ordering::SeqCst));
+        map['a'] += 10;
+        let mut copy = map.clone();
+        copy['b'] += 20;
+        assert_eq!(map['a'], 11);
+        assert_eq!(copy['b'], 22);
+        assert_eq!(map['z'], 0);
+        assert_eq!(made.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn default_ref() {
+        static TEMPLATE: &str = "template";
+
+        let mut map: EasyMap<char, Cow<str>> = EasyMap::new_with_default_ref(TEMPLATE);
+        assert_eq!(map['a'], "template");
+        map['b'] += "!";
+        assert_eq!(map['b'], "template!");
+        let _ = &mut map['c'];
+        assert!(matches!(map['c'], Cow::Borrowed(s) if std::ptr::eq(s, TEMPLATE)));
+        assert!(matches!(map['b'], Cow::Owned(_)));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn pivot_table() {
+        let records = vec![("a", 1, 'x'), ("a", 2, 'y'), ("b", 1, 'z'), ("a", 1, 'w')];
+        let table = pivot(records, |r| r.0, |r| r.1, |r| r.2);
+        assert_eq!(table.len(), 2);
+        assert_eq!(table["a"].len(), 2);
+        assert_eq!(table["a"][1], 'w');
+        assert_eq!(table["a"][2], 'y');
+        assert_eq!(table["b"][1], 'z');
+        assert_eq!(table["b"][2], '\0');
+        assert!(table["c"].is_empty());
+    }
+
+    #[test]
+    fn joins() {
+        let a = map! {'?'; "x" => 'a', "y" => 'b', "z" => 'c'};
+        let b = map! {9; "y" => 1, "z" => 2, "w" => 3};
+        let inner = a.join(&b);
+        assert_eq!(inner, map! {('?', 9); "y" => ('b', 1), "z" => ('c', 2)});
+        assert_eq!(inner["w"], ('?', 9));
+        let left = a.left_join(&b);
+        assert_eq!(left.len(), 3);
+        assert_eq!(left["x"], ('a', 9));
+        assert_eq!(left["z"], ('c', 2));
+        assert!(!left.contains_key("w"));
+    }
+
+    #[test]
+    fn semi_and_anti_joins() {
+        let map = map! {-1; 1 => 10, 2 => 20, 3 => 30};
+        let kept = map.semi_join(vec![1, 3, 5]);
+        assert_eq!(kept, map! {-1; 1 => 10, 3 => 30});
+        assert_eq!(kept[2], -1);
+        let removed = map.anti_join(crate::set! {1, 3, 5});
+        assert_eq!(removed, map! {-1; 2 => 20});
+        assert_eq!(removed[1], -1);
+        assert_eq!(map.semi_join(Vec::new()), map! {-1});
+        assert_eq!(map.len(), 3);
+
+        let keys = crate::set! {2, 4};
+        assert_eq!(map.semi_join(&keys), map! {-1; 2 => 20});
+        assert_eq!(map.anti_join(&keys).len(), 2);
+        assert_eq!(keys.len(), 2);
+    }
+
+    #[test]
+    fn macro_nested_default() {
+        let d: EasyMap<&str, EasyMap<&str, i32>> =
+            map! {map!{-1}; "a" => map!{"x" => 1}, "b" => map!{}, "c" => map!{2},};
+        assert_eq!(d["a"]["x"], 1);
+        assert_eq!(d["a"]["y"], -1);
+        assert_eq!(d["b"]["y"], -1);
+        assert_eq!(d["c"]["y"], 2);
+
+        let deep = map! {map!{map!{9}}; 0 => map!{0 => map!{0 => 1}}};
+        assert_eq!(deep[0][0][0], 1);
+        assert_eq!(deep[0][0][1], 9);
+        assert_eq!(deep[0][1][0], 9);
+    }
 }
@@ -0,0 +1,295 @@
+use crate::EasyMap;
+use std::iter::FromIterator;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+/// A sparse 2D matrix, which stores its cells in an `EasyMap` keyed by `(row, col)`. Cells which haven't been set
+/// return the default value, and the dimensions grow to fit the cells which have been set.
+///
+/// ```rust
+/// use easy_collections::EasyMatrix;
+///
+/// let mut m = EasyMatrix::new();
+/// m[(0, 0)] = 1;
+/// m[(1, 2)] = 5;
+/// assert_eq!(m.dims(), (2, 3));
+/// assert_eq!(m.row(1).collect::<Vec<_>>(), vec![&0, &0, &5]);
+/// assert_eq!(m.col(0).collect::<Vec<_>>(), vec![&1, &0]);
+/// assert_eq!(m.transpose()[(2, 1)], 5);
+/// ```
+///
+/// Matrices can be added, subtracted and multiplied element-wise, using the default value for missing cells:
+/// ```rust
+/// use easy_collections::EasyMatrix;
+///
+/// let a = EasyMatrix::from(vec![vec![1, 2], vec![3, 4]]);
+/// let b = EasyMatrix::from(vec![vec![10], vec![20], vec![30]]);
+/// assert_eq!(&a + &b, EasyMatrix::from(vec![vec![11, 2], vec![23, 4], vec![30, 0]]));
+/// assert_eq!(&a * &b, EasyMatrix::from(vec![vec![10, 0], vec![60, 0], vec![0, 0]]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EasyMatrix<V> {
+    pub(crate) cells: EasyMap<(usize, usize), V>,
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+}
+
+impl<V: Default> EasyMatrix<V> {
+    /// Create a new, empty `EasyMatrix`. The value `V` must implement `Default`.
+    pub fn new() -> EasyMatrix<V> {
+        EasyMatrix {
+            cells: EasyMap::new(),
+            rows: 0,
+            cols: 0,
+        }
+    }
+}
+
+impl<V> EasyMatrix<V> {
+    /// Create a new, empty `EasyMatrix`, with the value to use for cells which haven't been set.
+    pub fn new_with_default(default: V) -> EasyMatrix<V>
+    where
+        V: Clone,
+    {
+        EasyMatrix {
+            cells: EasyMap::new_with_default(default),
+            rows: 0,
+            cols: 0,
+        }
+    }
+
+    /// Returns the default value, which is used for cells which haven't been set.
+    pub fn default_value(&self) -> &V {
+        self.cells.default_value()
+    }
+
+    /// Returns the number of rows, which is one more than the largest row of any cell that's been set.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns, which is one more than the largest column of any cell that's been set.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of rows and columns.
+    pub fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Sets the value of a cell, returning its previous value if it was set.
+    pub fn insert(&mut self, row: usize, col: usize, v: V) -> Option<V> {
+        self.grow(row, col);
+        self.cells.insert((row, col), v)
+    }
+
+    /// Returns an iterator over the cells of a row, from the first column to the last.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &V> + '_ {
+        (0..self.cols).map(move |col| &self[(row, col)])
+    }
+
+    /// Returns an iterator over the cells of a column, from the first row to the last.
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &V> + '_ {
+        (0..self.rows).map(move |row| &self[(row, col)])
+    }
+
+    /// Returns the cells which have been set, and their positions.
+    pub fn cells(&self) -> &EasyMap<(usize, usize), V> {
+        &self.cells
+    }
+
+    /// Returns a copy of the matrix with its rows and columns swapped.
+    pub fn transpose(&self) -> EasyMatrix<V>
+    where
+        V: Clone,
+    {
+        let mut transposed = EasyMatrix::new_with_default(self.default_value().clone());
+        for (&(row, col), v) in self.cells.iter() {
+            transposed.insert(col, row, v.clone());
+        }
+
+        // keep the dimensions, even if the last rows or columns are empty
+        transposed.rows = self.cols;
+        transposed.cols = self.rows;
+        transposed
+    }
+
+    fn grow(&mut self, row: usize, col: usize) {
+        self.rows = self.rows.max(row + 1);
+        self.cols = self.cols.max(col + 1);
+    }
+
+    /// Combines two matrices cell by cell, using the default value for any cell that's only set in one of them.
+    fn zip_with(&self, other: &EasyMatrix<V>, f: impl Fn(V, V) -> V) -> EasyMatrix<V>
+    where
+        V: Clone,
+    {
+        let default = f(self.default_value().clone(), other.default_value().clone());
+        let mut result = EasyMatrix::new_with_default(default);
+        for &pos in self.cells.keys().chain(other.cells.keys()) {
+            if !result.cells.contains_key(&pos) {
+                let v = f(self[pos].clone(), other[pos].clone());
+                result.cells.insert(pos, v);
+            }
+        }
+
+        result.rows = self.rows.max(other.rows);
+        result.cols = self.cols.max(other.cols);
+        result
+    }
+}
+
+impl<V: Default> Default for EasyMatrix<V> {
+    fn default() -> Self {
+        EasyMatrix::new()
+    }
+}
+
+/// Matrices are equal if they have the same dimensions and the same value in every cell, whether or not the cell has
+/// been set.
+impl<V: PartialEq> PartialEq for EasyMatrix<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dims() == other.dims() && self.cells.semantically_eq(&other.cells)
+    }
+}
+
+impl<V: Eq> Eq for EasyMatrix<V> {}
+
+/// Creates a matrix from its rows. Rows can be different lengths, and the matrix is as wide as the longest one.
+impl<V: Default> From<Vec<Vec<V>>> for EasyMatrix<V> {
+    fn from(rows: Vec<Vec<V>>) -> Self {
+        let mut matrix = EasyMatrix::new();
+        matrix.rows = rows.len();
+        for (row, values) in rows.into_iter().enumerate() {
+            for (col, v) in values.into_iter().enumerate() {
+                matrix.insert(row, col, v);
+            }
+        }
+
+        matrix
+    }
+}
+
+impl<V: Default> FromIterator<((usize, usize), V)> for EasyMatrix<V> {
+    fn from_iter<T: IntoIterator<Item = ((usize, usize), V)>>(iter: T) -> Self {
+        let mut matrix = EasyMatrix::new();
+        for ((row, col), v) in iter {
+            matrix.insert(row, col, v);
+        }
+
+        matrix
+    }
+}
+
+impl<V> Index<(usize, usize)> for EasyMatrix<V> {
+    type Output = V;
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        &self.cells[pos]
+    }
+}
+impl<V> IndexMut<(usize, usize)> for EasyMatrix<V> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+        self.grow(pos.0, pos.1);
+        &mut self.cells[pos]
+    }
+}
+
+macro_rules! impl_element_op {
+    ($trait:ident, $method:ident) => {
+        impl<V: Clone + $trait<Output = V>> $trait<&EasyMatrix<V>> for &EasyMatrix<V> {
+            type Output = EasyMatrix<V>;
+            fn $method(self, rhs: &EasyMatrix<V>) -> Self::Output {
+                self.zip_with(rhs, V::$method)
+            }
+        }
+        impl<V: Clone + $trait<Output = V>> $trait for EasyMatrix<V> {
+            type Output = EasyMatrix<V>;
+            fn $method(self, rhs: EasyMatrix<V>) -> Self::Output {
+                self.zip_with(&rhs, V::$method)
+            }
+        }
+    };
+}
+
+impl_element_op!(Add, add);
+impl_element_op!(Sub, sub);
+impl_element_op!(Mul, mul);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn index_and_dims() {
+        let mut m = EasyMatrix::new_with_default('.');
+        assert_eq!(m.dims(), (0, 0));
+        assert_eq!(m[(5, 5)], '.');
+        assert_eq!(m.dims(), (0, 0));
+
+        m[(2, 0)] = '#';
+        assert_eq!(m.insert(0, 3, '#'), None);
+        assert_eq!(m.insert(0, 3, '@'), Some('#'));
+        assert_eq!((m.rows(), m.cols()), (3, 4));
+        assert_eq!(m.cells().len(), 2);
+
+        let rows = (0..m.rows())
+            .map(|r| m.row(r).collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec!["...@", "....", "#..."]);
+        assert_eq!(m.col(3).collect::<String>(), "@..");
+    }
+
+    #[test]
+    fn from_rows() {
+        let m = EasyMatrix::from(vec![vec![1, 2, 3], vec![], vec![4]]);
+        assert_eq!(m.dims(), (3, 3));
+        assert_eq!(m[(2, 0)], 4);
+        assert_eq!(m[(1, 1)], 0);
+        assert_eq!(
+            m,
+            EasyMatrix::from(vec![vec![1, 2, 3], vec![0], vec![4, 0, 0]])
+        );
+        assert_ne!(
+            m,
+            EasyMatrix::from(vec![vec![1, 2, 3], vec![], vec![4], vec![]])
+        );
+
+        let m = vec![((1, 1), 'x')].into_iter().collect::<EasyMatrix<_>>();
+        assert_eq!(m.dims(), (2, 2));
+    }
+
+    #[test]
+    fn transpose() {
+        let m = EasyMatrix::from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![]]);
+        let t = m.transpose();
+        assert_eq!(t.dims(), (3, 3));
+        assert_eq!(t.row(0).collect::<Vec<_>>(), vec![&1, &4, &0]);
+        assert_eq!(t.row(2).collect::<Vec<_>>(), vec![&3, &6, &0]);
+        assert_eq!(t.transpose(), m);
+    }
+
+    #[test]
+    fn element_ops() {
+        let mut a = EasyMatrix::new_with_default(1);
+        a[(0, 0)] = 5;
+        let mut b = EasyMatrix::new_with_default(2);
+        b[(1, 1)] = 7;
+
+        let sum = &a + &b;
+        assert_eq!(sum.dims(), (2, 2));
+        assert_eq!(sum[(0, 0)], 7);
+        assert_eq!(sum[(1, 1)], 8);
+        assert_eq!(sum[(0, 1)], 3);
+        assert_eq!(sum.cells().len(), 2);
+
+        let diff = a.clone() - b.clone();
+        assert_eq!(diff[(0, 0)], 3);
+        assert_eq!(diff[(1, 1)], -6);
+        assert_eq!(diff[(9, 9)], -1);
+
+        let product = a * b;
+        assert_eq!(product[(0, 0)], 10);
+        assert_eq!(product[(1, 1)], 7);
+        assert_eq!(product[(1, 0)], 2);
+    }
+}
@@ -0,0 +1,207 @@
+use std::hash::Hash;
+
+use crate::EasyMap;
+
+type MemoFn<'a, K, V> = dyn Fn(&mut EasyMemoHandle<'_, K, V>, K) -> V + 'a;
+
+/// A memoization wrapper, which caches the results of calling a function.
+///
+/// ```rust
+/// use easy_collections::EasyMemo;
+///
+/// let mut double = EasyMemo::new(|n: u64| n * 2);
+/// assert_eq!(double.get(21), 42);
+/// assert_eq!(double.len(), 1);
+/// ```
+///
+/// Recursive functions are supported via `EasyMemo::new_recursive`, where the function is given a handle which can be
+/// used to call itself (and hit the cache):
+/// ```rust
+/// use easy_collections::EasyMemo;
+///
+/// let mut fib = EasyMemo::new_recursive(|fib, n: u64| match n {
+///     0 | 1 => n,
+///     n => fib.get(n - 1) + fib.get(n - 2),
+/// });
+/// assert_eq!(fib.get(90), 2880067194370816120);
+/// ```
+///
+/// The cache is an `EasyMap`, so reading a result which hasn't been computed yet returns the default value:
+/// ```rust
+/// use easy_collections::EasyMemo;
+///
+/// let mut square = EasyMemo::new_with_default(-1, |n: i32| n * n);
+/// square.get(3);
+/// assert_eq!(square.cache()[3], 9);
+/// assert_eq!(square.cache()[4], -1);
+/// ```
+pub struct EasyMemo<'a, K: Eq + Hash, V> {
+    cache: EasyMap<K, V>,
+    func: Box<MemoFn<'a, K, V>>,
+}
+
+/// A handle to an `EasyMemo`, passed to recursive functions so they can call themselves.
+pub struct EasyMemoHandle<'a, K: Eq + Hash, V> {
+    cache: &'a mut EasyMap<K, V>,
+    func: &'a MemoFn<'a, K, V>,
+}
+
+impl<'a, K: Eq + Hash + Clone, V: Default> EasyMemo<'a, K, V> {
+    /// Create a new `EasyMemo` from a function. The value `V` must implement `Default`, which is what the cache
+    /// returns for results which haven't been computed.
+    pub fn new(f: impl Fn(K) -> V + 'a) -> EasyMemo<'a, K, V> {
+        EasyMemo::new_recursive(move |_, k| f(k))
+    }
+
+    /// Create a new `EasyMemo` from a function which is passed a handle to itself, for recursion.
+    pub fn new_recursive(
+        f: impl Fn(&mut EasyMemoHandle<'_, K, V>, K) -> V + 'a,
+    ) -> EasyMemo<'a, K, V> {
+        EasyMemo {
+            cache: EasyMap::new(),
+            func: Box::new(f),
+        }
+    }
+}
+
+impl<'a, K: Eq + Hash + Clone, V> EasyMemo<'a, K, V> {
+    /// Create a new `EasyMemo` from a function. The value `V` does not need to implement `Default`, instead you
+    /// provide the cache with one here.
+    pub fn new_with_default(default: V, f: impl Fn(K) -> V + 'a) -> EasyMemo<'a, K, V>
+    where
+        V: Clone,
+    {
+        EasyMemo::new_recursive_with_default(default, move |_, k| f(k))
+    }
+
+    /// Create a new `EasyMemo` from a function which is passed a handle to itself, with a default value for the cache.
+    pub fn new_recursive_with_default(
+        default: V,
+        f: impl Fn(&mut EasyMemoHandle<'_, K, V>, K) -> V + 'a,
+    ) -> EasyMemo<'a, K, V>
+    where
+        V: Clone,
+    {
+        EasyMemo {
+            cache: EasyMap::new_with_default(default),
+            func: Box::new(f),
+        }
+    }
+
+    /// Returns the cached result for `k`, calling the function (and caching its result) if it's not cached yet.
+    pub fn get(&mut self, k: K) -> V
+    where
+        V: Clone,
+    {
+        EasyMemoHandle {
+            cache: &mut self.cache,
+            func: &*self.func,
+        }
+        .get(k)
+    }
+
+    /// Returns the cached results.
+    pub fn cache(&self) -> &EasyMap<K, V> {
+        &self.cache
+    }
+
+    /// Returns whether a result for `k` has been cached.
+    pub fn is_cached(&self, k: &K) -> bool {
+        self.cache.contains_key(k)
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns whether there are no cached results.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<'a, K: Eq + Hash + Clone, V> EasyMemoHandle<'a, K, V> {
+    /// Same as `EasyMemo::get`.
+    pub fn get(&mut self, k: K) -> V
+    where
+        V: Clone,
+    {
+        if let Some(v) = self.cache.get(&k) {
+            return v.clone();
+        }
+
+        let func = self.func;
+        let v = func(self, k.clone());
+        self.cache.insert(k, v.clone());
+        v
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn caches_results() {
+        let calls = Cell::new(0);
+        let mut memo = EasyMemo::new(|s: &str| {
+            calls.set(calls.get() + 1);
+            s.len()
+        });
+
+        assert_eq!(memo.get("foo"), 3);
+        assert_eq!(memo.get("foo"), 3);
+        assert_eq!(memo.get("hello"), 5);
+        assert_eq!(calls.get(), 2);
+
+        assert_eq!(memo.len(), 2);
+        assert!(memo.is_cached(&"foo"));
+        assert!(!memo.is_cached(&"bar"));
+        assert_eq!(memo.cache()["hello"], 5);
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.get("foo"), 3);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn recursion() {
+        let calls = Cell::new(0);
+        let mut fib = EasyMemo::new_recursive(|fib, n: u64| {
+            calls.set(calls.get() + 1);
+            match n {
+                0 | 1 => n,
+                n => fib.get(n - 1) + fib.get(n - 2),
+            }
+        });
+
+        assert_eq!(fib.get(50), 12586269025);
+        // each value is only computed once
+        assert_eq!(calls.get(), 51);
+        assert_eq!(fib.len(), 51);
+    }
+
+    #[test]
+    fn cache_defaults() {
+        let mut memo = EasyMemo::new(|n: u8| n + 1);
+        memo.get(1);
+        assert_eq!(memo.cache()[1], 2);
+        assert_eq!(memo.cache()[2], 0);
+
+        let mut memo = EasyMemo::new_recursive_with_default('?', |memo, n: u32| match n {
+            0 => 'a',
+            n => std::char::from_u32(memo.get(n - 1) as u32 + 1).unwrap(),
+        });
+        assert_eq!(memo.get(2), 'c');
+        assert_eq!(memo.cache()[1], 'b');
+        assert_eq!(memo.cache()[3], '?');
+    }
+}
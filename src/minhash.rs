@@ -0,0 +1,107 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::EasySet;
+
+/// A MinHash signature of a set, created with `EasySet::minhash`. Comparing the signatures of two sets estimates their
+/// Jaccard similarity, which is much faster than intersecting large sets.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MinHashSignature {
+    mins: Vec<u64>,
+}
+
+impl MinHashSignature {
+    /// Returns the number of hashes in the signature.
+    pub fn len(&self) -> usize {
+        self.mins.len()
+    }
+
+    /// Returns whether the signature has no hashes.
+    pub fn is_empty(&self) -> bool {
+        self.mins.is_empty()
+    }
+
+    /// Estimates the Jaccard similarity of the sets the signatures were created from, which is the size of their
+    /// intersection divided by the size of their union. The error of the estimate is about `1 / sqrt(num_hashes)`.
+    ///
+    /// Panics if the signatures have a different number of hashes.
+    pub fn estimated_jaccard(&self, other: &MinHashSignature) -> f64 {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "signatures with a different number of hashes can't be compared"
+        );
+
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        let matches = self
+            .mins
+            .iter()
+            .zip(&other.mins)
+            .filter(|(a, b)| a == b)
+            .count();
+        matches as f64 / self.len() as f64
+    }
+}
+
+impl<K: Eq + Hash> EasySet<K> {
+    /// Returns the MinHash signature of the set, made of `num_hashes` hashes. More hashes give a more accurate estimate
+    /// of similarity, but take longer to compute.
+    ///
+    /// ```rust
+    /// use easy_collections::EasySet;
+    ///
+    /// let a = (0..1000).collect::<EasySet<_>>();
+    /// let b = (250..1250).collect::<EasySet<_>>();
+    /// // the exact similarity is 750 / 1250 = 0.6
+    /// let similarity = a.minhash(256).estimated_jaccard(&b.minhash(256));
+    /// assert!((similarity - 0.6).abs() < 0.1);
+    /// ```
+    pub fn minhash(&self, num_hashes: usize) -> MinHashSignature {
+        let mut mins = vec![u64::MAX; num_hashes];
+        for k in self {
+            for (seed, min) in mins.iter_mut().enumerate() {
+                let mut hasher = DefaultHasher::new();
+                seed.hash(&mut hasher);
+                k.hash(&mut hasher);
+                *min = (*min).min(hasher.finish());
+            }
+        }
+
+        MinHashSignature { mins }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::set;
+
+    #[test]
+    fn estimated_jaccard() {
+        let a = set! {"a", "b", "c", "d"};
+        assert_eq!(a.minhash(64).estimated_jaccard(&a.minhash(64)), 1.0);
+        assert_eq!(a.minhash(64), a.clone().minhash(64));
+        assert_eq!(
+            a.minhash(64)
+                .estimated_jaccard(&set! {"w", "x", "y", "z"}.minhash(64)),
+            0.0
+        );
+
+        let b = (0..2000).collect::<crate::EasySet<_>>();
+        let c = (1000..3000).collect::<crate::EasySet<_>>();
+        let similarity = b.minhash(512).estimated_jaccard(&c.minhash(512));
+        assert!((similarity - 1.0 / 3.0).abs() < 0.07, "{}", similarity);
+
+        let empty = crate::EasySet::<u8>::new().minhash(0);
+        assert!(empty.is_empty());
+        assert_eq!(empty.estimated_jaccard(&set! {1}.minhash(0)), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "signatures with a different number of hashes can't be compared")]
+    fn different_lengths() {
+        set! {1}.minhash(1).estimated_jaccard(&set! {1}.minhash(2));
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::ops::{Deref, Index};
+
+use crate::EasyMap;
+
+/// A map whose entries can only be written once, which is useful for things like symbol tables, where overwriting an
+/// entry is a bug. Reading keys which aren't in the map returns a default value, the same as `EasyMap`.
+///
+/// ```rust
+/// use easy_collections::EasyOnceMap;
+///
+/// let mut symbols = EasyOnceMap::new();
+/// assert!(symbols.try_insert("main", 0x1000).is_ok());
+/// let err = symbols.try_insert("main", 0x2000).unwrap_err();
+/// assert_eq!(err.to_string(), "the key is already in the map: \"main\"");
+/// assert_eq!(symbols["main"], 0x1000);
+/// assert_eq!(symbols["missing"], 0);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EasyOnceMap<K: Eq + Hash, V> {
+    pub(crate) inner: EasyMap<K, V>,
+}
+
+impl<K: Eq + Hash, V: Default> EasyOnceMap<K, V> {
+    /// Create a new `EasyOnceMap`. The value `V` must implement `Default`.
+    pub fn new() -> EasyOnceMap<K, V> {
+        EasyOnceMap {
+            inner: EasyMap::new(),
+        }
+    }
+
+    /// Creates a map from an iterator, returning an error for the first key which is repeated.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyOnceMap;
+    ///
+    /// assert!(EasyOnceMap::try_from_iter(vec![(1, 'a'), (2, 'b')]).is_ok());
+    /// let err = EasyOnceMap::try_from_iter(vec![(1, 'a'), (1, 'b')]).unwrap_err();
+    /// assert_eq!((err.key, err.value), (1, 'b'));
+    /// ```
+    pub fn try_from_iter(
+        iter: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<EasyOnceMap<K, V>, Occupied<K, V>> {
+        let mut map = EasyOnceMap::new();
+        for (k, v) in iter {
+            map.try_insert(k, v)?;
+        }
+
+        Ok(map)
+    }
+}
+
+impl<K: Eq + Hash, V> EasyOnceMap<K, V> {
+    /// Create a new `EasyOnceMap`. The value `V` does not need to implement `Default`, instead you provide it with one
+    /// here.
+    pub fn new_with_default(default: V) -> EasyOnceMap<K, V>
+    where
+        V: Clone,
+    {
+        EasyOnceMap {
+            inner: EasyMap::new_with_default(default),
+        }
+    }
+
+    /// Returns the default value, which is returned when reading keys which aren't in the map.
+    pub fn default_value(&self) -> &V {
+        self.inner.default_value()
+    }
+
+    /// Inserts `v` for `k` and returns a reference to it. If `k` is already in the map, it's left unchanged and the
+    /// key and value are returned in the error.
+    pub fn try_insert(&mut self, k: K, v: V) -> Result<&V, Occupied<K, V>> {
+        if self.inner.contains_key(&k) {
+            return Err(Occupied { key: k, value: v });
+        }
+
+        Ok(self.inner.entry(k).or_insert(v))
+    }
+
+    /// Consumes the map, returning an `EasyMap` with the same entries and default value, which can be overwritten.
+    pub fn into_map(self) -> EasyMap<K, V> {
+        self.inner
+    }
+}
+
+impl<K: Eq + Hash, V: Default> Default for EasyOnceMap<K, V> {
+    fn default() -> Self {
+        EasyOnceMap::new()
+    }
+}
+
+impl<K: Eq + Hash, V> IntoIterator for EasyOnceMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::collections::hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<K: Eq + Hash, V> Deref for EasyOnceMap<K, V> {
+    type Target = HashMap<K, V>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K: Eq + Hash, V> Index<K> for EasyOnceMap<K, V> {
+    type Output = V;
+    fn index(&self, key: K) -> &Self::Output {
+        &self.inner[key]
+    }
+}
+
+/// The error returned by `EasyOnceMap::try_insert` when the key is already in the map.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Occupied<K, V> {
+    /// The key which was already in the map.
+    pub key: K,
+    /// The value which wasn't inserted.
+    pub value: V,
+}
+
+impl<K: Debug, V> Display for Occupied<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the key is already in the map: {:?}", self.key)
+    }
+}
+
+impl<K: Debug, V: Debug> Error for Occupied<K, V> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::map;
+
+    #[test]
+    fn try_insert() {
+        let mut map = EasyOnceMap::new_with_default(vec![]);
+        assert_eq!(map.try_insert('a', vec![1]), Ok(&vec![1]));
+        assert_eq!(
+            map.try_insert('a', vec![2]),
+            Err(Occupied {
+                key: 'a',
+                value: vec![2]
+            })
+        );
+        assert_eq!(map['a'], vec![1]);
+        assert!(map['b'].is_empty());
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.into_map(), map! {vec![]; 'a' => vec![1]});
+    }
+
+    #[test]
+    fn try_from_iter() {
+        let map = EasyOnceMap::try_from_iter("abc".chars().zip(1..)).unwrap();
+        assert_eq!(map.into_iter().count(), 3);
+        let err = EasyOnceMap::try_from_iter("aba".chars().zip(1..)).unwrap_err();
+        assert_eq!(err, Occupied { key: 'a', value: 3 });
+    }
+
+    #[test]
+    fn values_without_clone() {
+        use std::sync::Mutex;
+
+        let mut map: EasyOnceMap<&str, Mutex<u8>> = EasyOnceMap::new();
+        assert!(map.try_insert("a", Mutex::new(1)).is_ok());
+        assert!(map.try_insert("a", Mutex::new(2)).is_err());
+        assert_eq!(*map["a"].lock().unwrap(), 1);
+        assert_eq!(*map["b"].lock().unwrap(), 0);
+    }
+}
@@ -0,0 +1,318 @@
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    collections::HashSet,
+    hash::Hash,
+    iter::FromIterator,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, Sub, SubAssign},
+};
+
+use paste::paste;
+
+#[macro_export]
+macro_rules! ordered_set {
+    () => {
+        $crate::EasyOrderedSet::new()
+    };
+    {$($key:expr$(,)?)*} => {{
+        let mut set = $crate::ordered_set!{};
+        $(set.insert($key);)*
+        set
+    }};
+}
+
+/// A set which remembers the order its items were first inserted in. It implements the same operators as `EasySet`
+/// (see its documentation), and their results keep the order of the left hand side, followed by any new items from the
+/// right hand side.
+///
+/// ```rust
+/// use easy_collections::{ordered_set, EasyOrderedSet};
+///
+/// let set = "mississippi".chars().collect::<EasyOrderedSet<_>>();
+/// assert_eq!(*set, ['m', 'i', 's', 'p']);
+///
+/// let a = &ordered_set!{3, 1, 2};
+/// let b = &ordered_set!{4, 2, 3};
+/// assert_eq!(*(a & b), [3, 2]);
+/// assert_eq!(*(a | b), [3, 1, 2, 4]);
+/// assert_eq!(*(a ^ b), [1, 4]);
+/// assert_eq!(*(a - b), [1]);
+/// ```
+///
+/// Equality doesn't depend on the order of the items, the same as any other set:
+/// ```rust
+/// use easy_collections::ordered_set;
+///
+/// assert_eq!(ordered_set!{1, 2}, ordered_set!{2, 1});
+/// ```
+#[derive(Debug, Clone)]
+pub struct EasyOrderedSet<K: Eq + Hash + Clone> {
+    pub(crate) items: Vec<K>,
+    pub(crate) seen: HashSet<K>,
+}
+
+impl<K: Eq + Hash + Clone> EasyOrderedSet<K> {
+    /// Create a new `EasyOrderedSet`.
+    pub fn new() -> EasyOrderedSet<K> {
+        EasyOrderedSet {
+            items: Vec::new(),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Adds an item to the end of the set, if it wasn't already in it. Returns whether it was newly inserted.
+    pub fn insert(&mut self, k: K) -> bool {
+        let inserted = self.seen.insert(k.clone());
+        if inserted {
+            self.items.push(k);
+        }
+
+        inserted
+    }
+
+    /// Same as `HashSet::contains`.
+    pub fn contains(&self, k: &K) -> bool {
+        self.seen.contains(k)
+    }
+
+    /// Removes an item from the set, keeping the order of the other items. Returns whether it was in the set.
+    ///
+    /// Note that this is `O(n)`, since the items after it have to be shifted.
+    pub fn remove(&mut self, k: &K) -> bool {
+        let removed = self.seen.remove(k);
+        if removed {
+            self.items.retain(|item| item != k);
+        }
+
+        removed
+    }
+
+    /// Inserts every item from `iter` into the set, the same as Python's `set.update`.
+    pub fn update(&mut self, iter: impl IntoIterator<Item = K>) {
+        for k in iter {
+            self.insert(k);
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> Default for EasyOrderedSet<K> {
+    fn default() -> Self {
+        EasyOrderedSet::new()
+    }
+}
+
+impl<K: Eq + Hash + Clone> PartialEq for EasyOrderedSet<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.seen == other.seen
+    }
+}
+
+impl<K: Eq + Hash + Clone> Eq for EasyOrderedSet<K> {}
+
+impl<K: Eq + Hash + Clone> PartialOrd for EasyOrderedSet<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Eq + Hash + Clone> Ord for EasyOrderedSet<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.seen.is_subset(&other.seen) {
+            Ordering::Less
+        } else if self.seen.is_superset(&other.seen) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> From<Vec<K>> for EasyOrderedSet<K> {
+    fn from(v: Vec<K>) -> Self {
+        v.into_iter().collect()
+    }
+}
+
+impl<K: Eq + Hash + Clone> From<&[K]> for EasyOrderedSet<K> {
+    fn from(v: &[K]) -> Self {
+        v.iter().cloned().collect()
+    }
+}
+
+impl<K: Eq + Hash + Clone> From<EasyOrderedSet<K>> for Vec<K> {
+    fn from(easy: EasyOrderedSet<K>) -> Self {
+        easy.items
+    }
+}
+
+impl<K: Eq + Hash + Clone> FromIterator<K> for EasyOrderedSet<K> {
+    fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
+        let mut set = EasyOrderedSet::new();
+        set.update(iter);
+        set
+    }
+}
+
+impl<K: Eq + Hash + Clone> Extend<K> for EasyOrderedSet<K> {
+    fn extend<T: IntoIterator<Item = K>>(&mut self, iter: T) {
+        self.update(iter);
+    }
+}
+
+impl<K: Eq + Hash + Clone> IntoIterator for EasyOrderedSet<K> {
+    type Item = K;
+    type IntoIter = std::vec::IntoIter<K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, K: Eq + Hash + Clone> IntoIterator for &'a EasyOrderedSet<K> {
+    type Item = &'a K;
+    type IntoIter = std::slice::Iter<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+/// The items can be read as a slice, in the order they were inserted. There's no `DerefMut`, since changing the items
+/// directly could add duplicates.
+impl<K: Eq + Hash + Clone> Deref for EasyOrderedSet<K> {
+    type Target = [K];
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($trait:ident, $method:ident, |$this:ident, $rhs:ident| $op:expr) => {
+        paste! {
+            impl<K: Eq + Hash + Clone, T: Into<EasyOrderedSet<K>>> $trait<T> for &EasyOrderedSet<K> {
+                type Output = EasyOrderedSet<K>;
+                fn $method(self, rhs: T) -> Self::Output {
+                    self.$method(&rhs.into())
+                }
+            }
+            impl<K: Eq + Hash + Clone, T: Into<EasyOrderedSet<K>>> $trait<T> for EasyOrderedSet<K> {
+                type Output = Self;
+                fn $method(self, rhs: T) -> Self::Output {
+                    (&self).$method(&rhs.into())
+                }
+            }
+            impl<K: Eq + Hash + Clone, T: Into<EasyOrderedSet<K>>> [<$trait Assign>]<T> for EasyOrderedSet<K> {
+                fn [<$method _assign>](&mut self, rhs: T) {
+                    self.[<$method _assign>](&rhs.into())
+                }
+            }
+            // borrowed sets are used directly, rather than being converted (and cloned)
+            impl<K: Eq + Hash + Clone> $trait<&EasyOrderedSet<K>> for &EasyOrderedSet<K> {
+                type Output = EasyOrderedSet<K>;
+                fn $method(self, $rhs: &EasyOrderedSet<K>) -> Self::Output {
+                    let $this = self;
+                    $op
+                }
+            }
+            impl<K: Eq + Hash + Clone> $trait<&EasyOrderedSet<K>> for EasyOrderedSet<K> {
+                type Output = Self;
+                fn $method(self, rhs: &EasyOrderedSet<K>) -> Self::Output {
+                    (&self).$method(rhs)
+                }
+            }
+            impl<K: Eq + Hash + Clone> [<$trait Assign>]<&EasyOrderedSet<K>> for EasyOrderedSet<K> {
+                fn [<$method _assign>](&mut self, rhs: &EasyOrderedSet<K>) {
+                    *self = (&*self).$method(rhs);
+                }
+            }
+        }
+    };
+}
+
+impl_bit_op! {
+    BitAnd, bitand,
+    |this, rhs| this.iter().filter(|k| rhs.contains(k)).cloned().collect()
+}
+impl_bit_op! {
+    BitOr, bitor,
+    |this, rhs| this.iter().chain(rhs.iter()).cloned().collect()
+}
+impl_bit_op! {
+    BitXor, bitxor,
+    |this, rhs| this
+        .iter()
+        .filter(|k| !rhs.contains(k))
+        .chain(rhs.iter().filter(|k| !this.contains(k)))
+        .cloned()
+        .collect()
+}
+impl_bit_op! {
+    Sub, sub,
+    |this, rhs| this.iter().filter(|k| !rhs.contains(k)).cloned().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn macros() {
+        let set: EasyOrderedSet<u8> = ordered_set! {};
+        assert!(set.is_empty());
+        let set = ordered_set! {3, 1, 3, 2};
+        assert_eq!(*set, [3, 1, 2]);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut set = EasyOrderedSet::new();
+        assert!(set.insert("b"));
+        assert!(set.insert("a"));
+        assert!(!set.insert("b"));
+        assert!(set.insert("c"));
+        assert_eq!(*set, ["b", "a", "c"]);
+
+        assert!(set.remove(&"a"));
+        assert!(!set.remove(&"a"));
+        assert!(!set.contains(&"a"));
+        assert_eq!(*set, ["b", "c"]);
+        set.insert("a");
+        assert_eq!(Vec::from(set), vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn eq_and_cmp() {
+        let a = ordered_set! {1, 2};
+        let b = ordered_set! {2, 1, 3};
+        assert_eq!(a, ordered_set! {2, 1});
+        assert_ne!(a, b);
+        assert!(a < b);
+        assert!(b > a);
+    }
+
+    #[test]
+    fn ops_keep_order() {
+        let a = ordered_set! {5, 4, 3, 2};
+        let b = ordered_set! {1, 2, 3, 6};
+
+        assert_eq!(*(&a & &b), [3, 2]);
+        assert_eq!(*(&b & &a), [2, 3]);
+        assert_eq!(*(&a | &b), [5, 4, 3, 2, 1, 6]);
+        assert_eq!(*(&a ^ &b), [5, 4, 1, 6]);
+        assert_eq!(*(&a - &b), [5, 4]);
+        assert_eq!(*(a.clone() - vec![4, 9]), [5, 3, 2]);
+        assert_eq!(*(a.clone() | [7, 5].as_ref()), [5, 4, 3, 2, 7]);
+
+        let mut c = a.clone();
+        c &= &b;
+        assert_eq!(*c, [3, 2]);
+        let mut c = a.clone();
+        c |= vec![0];
+        assert_eq!(*c, [5, 4, 3, 2, 0]);
+        let mut c = a.clone();
+        c ^= &b;
+        assert_eq!(*c, [5, 4, 1, 6]);
+        let mut c = a;
+        c -= &b;
+        assert_eq!(*c, [5, 4]);
+    }
+}
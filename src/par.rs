@@ -0,0 +1,145 @@
+use crate::{EasyMap, EasySet};
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+impl<K: Eq + Hash + Send, V: Default + Send> FromParallelIterator<(K, V)> for EasyMap<K, V> {
+    fn from_par_iter<T: IntoParallelIterator<Item = (K, V)>>(par_iter: T) -> Self {
+        let mut map = EasyMap::new();
+        *map = par_iter.into_par_iter().collect::<HashMap<_, _>>();
+        map
+    }
+}
+
+impl<K: Eq + Hash + Send, V: Send> ParallelExtend<(K, V)> for EasyMap<K, V> {
+    fn par_extend<T: IntoParallelIterator<Item = (K, V)>>(&mut self, par_iter: T) {
+        (**self).par_extend(par_iter);
+    }
+}
+
+impl<K: Eq + Hash + Send, V: Send> IntoParallelIterator for EasyMap<K, V> {
+    type Item = (K, V);
+    type Iter = rayon::collections::hash_map::IntoIter<K, V>;
+
+    fn into_par_iter(mut self) -> Self::Iter {
+        std::mem::take(&mut *self).into_par_iter()
+    }
+}
+
+impl<K: Eq + Hash + Send> EasyMap<K, usize> {
+    /// Counts the occurrences of each item in a parallel iterator, using all available threads.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    /// use rayon::prelude::*;
+    ///
+    /// let counts = EasyMap::par_counts((0..1000).into_par_iter().map(|n| n % 3));
+    /// assert_eq!(counts[0], 334);
+    /// assert_eq!(counts[1], 333);
+    /// assert_eq!(counts[2], 333);
+    /// assert_eq!(counts[3], 0);
+    /// ```
+    pub fn par_counts<T: IntoParallelIterator<Item = K>>(par_iter: T) -> EasyMap<K, usize> {
+        let mut map = EasyMap::new();
+        *map = par_iter
+            .into_par_iter()
+            .fold(HashMap::new, |mut counts, k| {
+                *counts.entry(k).or_insert(0) += 1;
+                counts
+            })
+            .reduce(HashMap::new, |mut a, mut b| {
+                if a.len() < b.len() {
+                    std::mem::swap(&mut a, &mut b);
+                }
+                for (k, n) in b {
+                    *a.entry(k).or_insert(0) += n;
+                }
+                a
+            });
+        map
+    }
+}
+
+impl<K: Eq + Hash + Send> FromParallelIterator<K> for EasySet<K> {
+    fn from_par_iter<T: IntoParallelIterator<Item = K>>(par_iter: T) -> Self {
+        par_iter.into_par_iter().collect::<HashSet<_>>().into()
+    }
+}
+
+impl<K: Eq + Hash + Send> ParallelExtend<K> for EasySet<K> {
+    fn par_extend<T: IntoParallelIterator<Item = K>>(&mut self, par_iter: T) {
+        (**self).par_extend(par_iter);
+    }
+}
+
+impl<K: Eq + Hash + Send> IntoParallelIterator for EasySet<K> {
+    type Item = K;
+    type Iter = rayon::collections::hash_set::IntoIter<K>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        HashSet::from(self).into_par_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{map, set};
+    use rayon::prelude::*;
+
+    #[test]
+    fn map_from_par_iter() {
+        let map = (0..100)
+            .into_par_iter()
+            .map(|n| (n, n * 2))
+            .collect::<EasyMap<_, _>>();
+        assert_eq!(map.len(), 100);
+        assert_eq!(map[50], 100);
+        assert_eq!(map[100], 0);
+    }
+
+    #[test]
+    fn map_par_extend() {
+        let mut map = map! {1; 'a' => 10};
+        map.par_extend(vec![('b', 20), ('c', 30)]);
+        assert_eq!(map, map! {1; 'a' => 10, 'b' => 20, 'c' => 30});
+        assert_eq!(map['d'], 1);
+    }
+
+    #[test]
+    fn map_into_par_iter() {
+        let map = map! {'a' => 1, 'b' => 2, 'c' => 3};
+        assert_eq!(map.into_par_iter().map(|(_, v)| v).sum::<i32>(), 6);
+    }
+
+    #[test]
+    fn par_counts() {
+        let counts = EasyMap::par_counts("hello world".par_chars());
+        assert_eq!(counts['l'], 3);
+        assert_eq!(counts['o'], 2);
+        assert_eq!(counts['h'], 1);
+        assert_eq!(counts['z'], 0);
+    }
+
+    #[test]
+    fn set_from_par_iter() {
+        let set = (0..10)
+            .into_par_iter()
+            .map(|n| n % 3)
+            .collect::<EasySet<_>>();
+        assert_eq!(set, set! {0, 1, 2});
+    }
+
+    #[test]
+    fn set_par_extend() {
+        let mut set = set! {1};
+        set.par_extend(vec![2, 3]);
+        assert_eq!(set, set! {1, 2, 3});
+    }
+
+    #[test]
+    fn set_into_par_iter() {
+        let set = set! {1, 2, 3};
+        assert_eq!(set.into_par_iter().sum::<i32>(), 6);
+    }
+}
@@ -0,0 +1,289 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+use std::ops::{Index, IndexMut};
+
+use crate::EasyMap;
+
+/// A value which may hold a nested `EasyMap` of more values, such as a JSON-like value. Maps of these can be walked
+/// with a path of keys, using `EasyMap::get_path` and `EasyMap::set_path`.
+///
+/// An `EasyMap` holds its default value inline, so the nested map needs to be boxed for the value type to have a size.
+pub trait NestedValue<K: Eq + Hash>: Sized {
+    /// Returns the nested map, if this value holds one.
+    fn as_map(&self) -> Option<&EasyMap<K, Self>>;
+
+    /// Returns the nested map mutably, if this value holds one.
+    fn as_map_mut(&mut self) -> Option<&mut EasyMap<K, Self>>;
+
+    /// Returns a value holding a new, empty nested map.
+    fn new_map() -> Self;
+}
+
+impl<K: Eq + Hash, V: NestedValue<K>> EasyMap<K, V> {
+    /// Returns the value at the end of `path`, looking up each key in the map held by the value for the previous key.
+    /// Returns `None` if a key is missing, if a value along the way doesn't hold a map, or if `path` is empty.
+    ///
+    /// ```rust
+    /// use easy_collections::{map, EasyMap, NestedValue};
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum Config {
+    ///     Port(u16),
+    ///     Section(Box<EasyMap<&'static str, Config>>),
+    /// }
+    ///
+    /// impl NestedValue<&'static str> for Config {
+    ///     fn as_map(&self) -> Option<&EasyMap<&'static str, Config>> {
+    ///         match self {
+    ///             Config::Section(map) => Some(map),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///
+    ///     fn as_map_mut(&mut self) -> Option<&mut EasyMap<&'static str, Config>> {
+    ///         match self {
+    ///             Config::Section(map) => Some(map),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///
+    ///     fn new_map() -> Config {
+    ///         Config::Section(Box::new(EasyMap::new_with_default(Config::Port(0))))
+    ///     }
+    /// }
+    ///
+    /// let mut config = EasyMap::new_with_default(Config::Port(0));
+    /// config.set_path(vec!["server", "http", "port"], Config::Port(80));
+    /// assert_eq!(config.get_path(&["server", "http", "port"]), Some(&Config::Port(80)));
+    /// assert_eq!(config.get_path(&["server", "ftp", "port"]), None);
+    /// ```
+    pub fn get_path<Q: Borrow<K>>(&self, path: impl IntoIterator<Item = Q>) -> Option<&V> {
+        let mut path = path.into_iter();
+        let mut value = self.inner.get(path.next()?.borrow())?;
+        for k in path {
+            value = value.as_map()?.inner.get(k.borrow())?;
+        }
+
+        Some(value)
+    }
+
+    /// Sets the value at the end of `path` to `v`, returning the value which was there before. Any missing keys along
+    /// the way are inserted with `NestedValue::new_map`, and so are values along the way which don't hold a map,
+    /// replacing them.
+    ///
+    /// Panics if `path` is empty.
+    pub fn set_path(&mut self, path: impl IntoIterator<Item = K>, v: V) -> Option<V> {
+        let mut path = path.into_iter().collect::<Vec<_>>();
+        let last = path.pop().expect("the path must have at least one key");
+
+        let mut map = self;
+        for k in path {
+            let value = map.inner.entry(k).or_insert_with(V::new_map);
+            if value.as_map().is_none() {
+                *value = V::new_map();
+            }
+            map = value.as_map_mut().unwrap();
+        }
+
+        map.inner.insert(last, v)
+    }
+}
+
+/// Indexing with a dotted path, such as `map["server.port"]`, walks the nested maps like `EasyMap::get_path`, with
+/// each part of the path as a key. If a key is missing, the default value of the map it's missing from is returned.
+/// Keys which contain a `.` can't be reached this way, but they can with `EasyMap::get_path`.
+///
+/// ```rust
+/// use easy_collections::{EasyMap, NestedValue};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Config {
+///     Port(u16),
+///     Section(Box<EasyMap<String, Config>>),
+/// }
+///
+/// impl NestedValue<String> for Config {
+///     fn as_map(&self) -> Option<&EasyMap<String, Config>> {
+///         match self {
+///             Config::Section(map) => Some(map),
+///             _ => None,
+///         }
+///     }
+///
+///     fn as_map_mut(&mut self) -> Option<&mut EasyMap<String, Config>> {
+///         match self {
+///             Config::Section(map) => Some(map),
+///             _ => None,
+///         }
+///     }
+///
+///     fn new_map() -> Config {
+///         Config::Section(Box::new(EasyMap::new_with_default(Config::Port(0))))
+///     }
+/// }
+///
+/// let mut config: EasyMap<String, Config> = EasyMap::new_with_default(Config::Port(0));
+/// config["server.port"] = Config::Port(8080);
+/// assert_eq!(config["server.port"], Config::Port(8080));
+/// assert_eq!(config["server.missing.port"], Config::Port(0));
+/// ```
+impl<V: NestedValue<String>> Index<&str> for EasyMap<String, V> {
+    type Output = V;
+    fn index(&self, path: &str) -> &Self::Output {
+        let mut map = self;
+        let mut parts = path.split('.').peekable();
+        while let Some(k) = parts.next() {
+            let value = match map.inner.get(k) {
+                Some(value) => value,
+                None => return &map.default,
+            };
+            if parts.peek().is_none() {
+                return value;
+            }
+            match value.as_map() {
+                Some(nested) => map = nested,
+                None => return &map.default,
+            }
+        }
+
+        unreachable!("splitting a string always returns at least one part")
+    }
+}
+
+/// Writing with a dotted path inserts any missing maps along the path, replacing values which don't hold a map, and
+/// inserts the default value of the innermost map if the last key is missing.
+impl<V: NestedValue<String>> IndexMut<&str> for EasyMap<String, V> {
+    fn index_mut(&mut self, path: &str) -> &mut Self::Output {
+        let mut parts = path.split('.').collect::<Vec<_>>();
+        let last = parts.pop().unwrap();
+
+        let mut map = self;
+        for k in parts {
+            let value = map.inner.entry(k.to_string()).or_insert_with(V::new_map);
+            if value.as_map().is_none() {
+                *value = V::new_map();
+            }
+            map = value.as_map_mut().unwrap();
+        }
+
+        let (default, fill) = (&map.default, &map.fill);
+        map.inner
+            .entry(last.to_string())
+            .or_insert_with(|| fill.make(default))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Tree {
+        Leaf(u8),
+        Node(Box<EasyMap<char, Tree>>),
+    }
+
+    impl NestedValue<char> for Tree {
+        fn as_map(&self) -> Option<&EasyMap<char, Tree>> {
+            match self {
+                Tree::Node(map) => Some(map),
+                Tree::Leaf(_) => None,
+            }
+        }
+
+        fn as_map_mut(&mut self) -> Option<&mut EasyMap<char, Tree>> {
+            match self {
+                Tree::Node(map) => Some(map),
+                Tree::Leaf(_) => None,
+            }
+        }
+
+        fn new_map() -> Tree {
+            Tree::Node(Box::new(EasyMap::new_with_default(Tree::Leaf(0))))
+        }
+    }
+
+    #[test]
+    fn paths() {
+        let mut tree = EasyMap::new_with_default(Tree::Leaf(0));
+        assert_eq!(tree.set_path("abc".chars(), Tree::Leaf(1)), None);
+        assert_eq!(
+            tree.set_path("abc".chars(), Tree::Leaf(2)),
+            Some(Tree::Leaf(1))
+        );
+        assert_eq!(tree.set_path("ad".chars(), Tree::Leaf(3)), None);
+        assert_eq!(tree.get_path("abc".chars()), Some(&Tree::Leaf(2)));
+        assert_eq!(tree.get_path(['a', 'd']), Some(&Tree::Leaf(3)));
+        assert!(tree.get_path("ab".chars()).unwrap().as_map().is_some());
+        assert_eq!(tree.get_path("abcd".chars()), None);
+        assert_eq!(tree.get_path("x".chars()), None);
+        assert_eq!(tree.get_path("".chars()), None);
+
+        // leaves along the path are replaced with maps
+        tree.set_path("adx".chars(), Tree::Leaf(4));
+        assert_eq!(tree.get_path("adx".chars()), Some(&Tree::Leaf(4)));
+        assert_eq!(tree.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the path must have at least one key")]
+    fn empty_path() {
+        EasyMap::new_with_default(Tree::Leaf(0)).set_path(vec![], Tree::Leaf(1));
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Value {
+        Int(i64),
+        Map(Box<EasyMap<String, Value>>),
+    }
+
+    impl NestedValue<String> for Value {
+        fn as_map(&self) -> Option<&EasyMap<String, Value>> {
+            match self {
+                Value::Map(map) => Some(map),
+                Value::Int(_) => None,
+            }
+        }
+
+        fn as_map_mut(&mut self) -> Option<&mut EasyMap<String, Value>> {
+            match self {
+                Value::Map(map) => Some(map),
+                Value::Int(_) => None,
+            }
+        }
+
+        fn new_map() -> Value {
+            Value::Map(Box::new(EasyMap::new_with_default(Value::Int(-1))))
+        }
+    }
+
+    #[test]
+    fn dotted() {
+        let mut map = EasyMap::new_with_default(Value::Int(0));
+        map["a.b.c"] = Value::Int(1);
+        map["a.d"] = Value::Int(2);
+        map["e"] = Value::Int(3);
+        assert_eq!(map["a.b.c"], Value::Int(1));
+        assert_eq!(map["a.d"], Value::Int(2));
+        assert_eq!(map["e"], Value::Int(3));
+        assert_eq!(
+            map.get_path(["a", "b", "c"].map(String::from)),
+            Some(&Value::Int(1))
+        );
+
+        // missing keys return the default of the map they're missing from
+        assert_eq!(map["x"], Value::Int(0));
+        assert_eq!(map["a.x"], Value::Int(-1));
+        assert_eq!(map["a.d.x"], Value::Int(-1));
+        assert_eq!(map["a.b.x.y"], Value::Int(-1));
+
+        // writing through a value which isn't a map replaces it
+        map["e.f"] = Value::Int(4);
+        assert_eq!(map["e.f"], Value::Int(4));
+        if let Value::Int(n) = &mut map["a.b.new"] {
+            *n += 10;
+        }
+        assert_eq!(map["a.b.new"], Value::Int(9));
+        assert_eq!(map.len(), 2);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{EasyMap, EasySet};
+use pyo3::conversion::{FromPyObjectOwned, IntoPyObject};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PySet};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+impl<'a, 'py, K, V> FromPyObject<'a, 'py> for EasyMap<K, V>
+where
+    K: FromPyObjectOwned<'py> + Eq + Hash,
+    V: FromPyObjectOwned<'py> + Default,
+{
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let mut map = EasyMap::new();
+        *map = HashMap::extract(obj)?;
+        Ok(map)
+    }
+}
+
+impl<'py, K, V> IntoPyObject<'py> for EasyMap<K, V>
+where
+    K: IntoPyObject<'py> + Eq + Hash,
+    V: IntoPyObject<'py>,
+{
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(mut self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        std::mem::take(&mut *self).into_pyobject(py)
+    }
+}
+
+impl<'a, 'py, K, V> IntoPyObject<'py> for &'a EasyMap<K, V>
+where
+    &'a K: IntoPyObject<'py> + Eq + Hash,
+    &'a V: IntoPyObject<'py>,
+    K: Eq + Hash,
+{
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (&**self).into_pyobject(py)
+    }
+}
+
+impl<'a, 'py, K> FromPyObject<'a, 'py> for EasySet<K>
+where
+    K: FromPyObjectOwned<'py> + Eq + Hash,
+{
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> Result<Self, Self::Error> {
+        Ok(HashSet::extract(obj)?.into())
+    }
+}
+
+impl<'py, K> IntoPyObject<'py> for EasySet<K>
+where
+    K: IntoPyObject<'py> + Eq + Hash,
+{
+    type Target = PySet;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        HashSet::from(self).into_pyobject(py)
+    }
+}
+
+impl<'a, 'py, K> IntoPyObject<'py> for &'a EasySet<K>
+where
+    &'a K: IntoPyObject<'py> + Eq + Hash,
+    K: Eq + Hash,
+{
+    type Target = PySet;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (&**self).into_pyobject(py)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{map, set};
+    use std::ffi::CString;
+
+    fn eval<'py>(py: Python<'py>, code: &str) -> Bound<'py, PyAny> {
+        py.eval(&CString::new(code).unwrap(), None, None).unwrap()
+    }
+
+    #[test]
+    fn map_round_trip() {
+        Python::initialize();
+        Python::attach(|py| {
+            let map: EasyMap<String, i32> = eval(py, "{'foo': 1, 'bar': 10}").extract().unwrap();
+            assert_eq!(map["foo".to_string()], 1);
+            assert_eq!(map["bar".to_string()], 10);
+            assert_eq!(map["baz".to_string()], 0);
+
+            let dict = (&map).into_pyobject(py).unwrap();
+            assert_eq!(dict.len(), 2);
+            let dict = map.into_pyobject(py).unwrap();
+            assert!(dict.eq(eval(py, "{'foo': 1, 'bar': 10}")).unwrap());
+
+            let map = map! {"a" => vec![1, 2]};
+            let dict = map.into_pyobject(py).unwrap();
+            assert!(dict.eq(eval(py, "{'a': [1, 2]}")).unwrap());
+        });
+    }
+
+    #[test]
+    fn set_round_trip() {
+        Python::initialize();
+        Python::attach(|py| {
+            let set: EasySet<u8> = eval(py, "{1, 2, 3}").extract().unwrap();
+            assert_eq!(set, set! {1, 2, 3});
+            let set: EasySet<u8> = eval(py, "frozenset([4])").extract().unwrap();
+            assert_eq!(set, set! {4});
+
+            let py_set = (&set).into_pyobject(py).unwrap();
+            assert_eq!(py_set.len(), 1);
+            let py_set = set! {"foo", "bar"}.into_pyobject(py).unwrap();
+            assert!(py_set.eq(eval(py, "{'foo', 'bar'}")).unwrap());
+        });
+    }
+
+    #[test]
+    fn extract_errors() {
+        Python::initialize();
+        Python::attach(|py| {
+            assert!(eval(py, "[1, 2]").extract::<EasyMap<u8, u8>>().is_err());
+            assert!(eval(py, "{'a': 'b'}")
+                .extract::<EasyMap<String, u8>>()
+                .is_err());
+            assert!(eval(py, "{'a'}").extract::<EasySet<u8>>().is_err());
+        });
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::VecDeque;
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+
+#[macro_export]
+macro_rules! queue {
+    () => {
+        $crate::EasyQueue::new()
+    };
+    [$($item:expr),+ $(,)?] => {
+        $crate::EasyQueue::from(vec![$($item),+])
+    };
+}
+
+/// A first-in, first-out queue, wrapping a `VecDeque`. Items are pushed onto the back and popped off the front.
+///
+/// ```rust
+/// use easy_collections::queue;
+///
+/// // the first item is the front of the queue
+/// let mut queue = queue![1, 2];
+/// queue.push(3).push(4);
+/// assert_eq!(queue.peek(), Some(&1));
+/// assert_eq!(queue.pop(), Some(1));
+/// assert_eq!(queue.pop(), Some(2));
+/// assert_eq!(queue.len(), 2);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EasyQueue<T> {
+    pub(crate) inner: VecDeque<T>,
+}
+
+impl<T> EasyQueue<T> {
+    /// Create a new, empty `EasyQueue`.
+    pub fn new() -> EasyQueue<T> {
+        EasyQueue {
+            inner: VecDeque::new(),
+        }
+    }
+
+    /// Pushes an item onto the back of the queue, returning the queue so calls can be chained.
+    pub fn push(&mut self, item: T) -> &mut EasyQueue<T> {
+        self.inner.push_back(item);
+        self
+    }
+
+    /// Removes the item from the front of the queue and returns it, or `None` if it's empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop_front()
+    }
+
+    /// Returns the item at the front of the queue, or `None` if it's empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.inner.front()
+    }
+
+    /// Returns whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T> Default for EasyQueue<T> {
+    fn default() -> Self {
+        EasyQueue::new()
+    }
+}
+
+/// The first item of the `Vec` is the front of the queue.
+impl<T> From<Vec<T>> for EasyQueue<T> {
+    fn from(v: Vec<T>) -> Self {
+        EasyQueue { inner: v.into() }
+    }
+}
+
+impl<T> From<VecDeque<T>> for EasyQueue<T> {
+    fn from(inner: VecDeque<T>) -> Self {
+        EasyQueue { inner }
+    }
+}
+
+impl<T> From<EasyQueue<T>> for VecDeque<T> {
+    fn from(easy: EasyQueue<T>) -> Self {
+        easy.inner
+    }
+}
+
+impl<T> FromIterator<T> for EasyQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        EasyQueue {
+            inner: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<T> for EasyQueue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+impl<T> Deref for EasyQueue<T> {
+    type Target = VecDeque<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for EasyQueue<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn macros() {
+        let queue: EasyQueue<u8> = queue![];
+        assert!(queue.is_empty());
+        let queue = queue![1, 2, 3,];
+        assert_eq!(queue.peek(), Some(&1));
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut queue = EasyQueue::new();
+        assert_eq!(queue.pop(), None);
+        assert_eq!(queue.peek(), None);
+
+        queue.push('a').push('b');
+        queue.extend(vec!['c']);
+        let mut popped = vec![];
+        while let Some(c) = queue.pop() {
+            popped.push(c);
+        }
+        assert_eq!(popped, vec!['a', 'b', 'c']);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn bfs() {
+        let edges = [vec![1, 2], vec![3], vec![3], vec![]];
+        let mut seen = vec![0];
+        let mut queue = queue![0];
+        while let Some(n) = queue.pop() {
+            for &next in &edges[n] {
+                if !seen.contains(&next) {
+                    seen.push(next);
+                    queue.push(next);
+                }
+            }
+        }
+        assert_eq!(seen, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{EasyMap, EasySet};
+use regex::Regex;
+use std::hash::Hash;
+
+impl<K: Eq + Hash + AsRef<str>, V> EasyMap<K, V> {
+    /// Returns an iterator over the keys which match `re`, in an arbitrary order.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    /// use regex::Regex;
+    ///
+    /// let map = map!{"user_id" => 1, "user_name" => 2, "session" => 3};
+    /// let re = Regex::new("^user_").unwrap();
+    /// let mut keys = map.keys_matching(&re).collect::<Vec<_>>();
+    /// keys.sort();
+    /// assert_eq!(keys, vec![&"user_id", &"user_name"]);
+    /// ```
+    pub fn keys_matching<'a>(&'a self, re: &'a Regex) -> impl Iterator<Item = &'a K> + 'a {
+        self.keys().filter(move |k| re.is_match(k.as_ref()))
+    }
+
+    /// Removes the entries whose keys don't match `re`.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    /// use regex::Regex;
+    ///
+    /// let mut map = map!{"a1" => 1, "b2" => 2, "c" => 3};
+    /// map.retain_matching(&Regex::new(r"\d$").unwrap());
+    /// assert_eq!(map, map!{"a1" => 1, "b2" => 2});
+    /// ```
+    pub fn retain_matching(&mut self, re: &Regex) {
+        self.retain(|k, _| re.is_match(k.as_ref()));
+    }
+}
+
+impl<K: Eq + Hash + AsRef<str>> EasySet<K> {
+    /// Returns an iterator over the items which match `re`, in an arbitrary order.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    /// use regex::Regex;
+    ///
+    /// let set = set!{"cat", "cart", "dog"};
+    /// assert_eq!(set.matching(&Regex::new("^ca").unwrap()).count(), 2);
+    /// ```
+    pub fn matching<'a>(&'a self, re: &'a Regex) -> impl Iterator<Item = &'a K> + 'a {
+        self.iter().filter(move |k| re.is_match(k.as_ref()))
+    }
+
+    /// Removes the items which don't match `re`.
+    pub fn retain_matching(&mut self, re: &Regex) {
+        self.retain(|k| re.is_match(k.as_ref()));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{map, set, EasyMap};
+    use regex::Regex;
+
+    #[test]
+    fn map() {
+        let re = Regex::new("^user_").unwrap();
+        let mut map: EasyMap<String, u8> = map! {
+            "user_a".to_string() => 1,
+            "user_b".to_string() => 2,
+            "admin_user_c".to_string() => 3,
+        };
+        let mut keys = map.keys_matching(&re).cloned().collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys, vec!["user_a", "user_b"]);
+
+        map.retain_matching(&re);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["admin_user_c".to_string()], 0);
+    }
+
+    #[test]
+    fn set() {
+        let re = Regex::new("^[a-z]+$").unwrap();
+        let mut set = set! {"abc", "a1", "", "xyz"};
+        assert_eq!(set.matching(&re).count(), 2);
+        set.retain_matching(&re);
+        assert_eq!(set, set! {"abc", "xyz"});
+    }
+}
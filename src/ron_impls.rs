@@ -0,0 +1,71 @@
+use crate::{EasyMap, EasySet};
+use ron::error::SpannedResult;
+use serde::Deserialize;
+use std::hash::Hash;
+
+impl<'de, K, V> EasyMap<K, V>
+where
+    K: Eq + Hash + Deserialize<'de>,
+    V: Default + Deserialize<'de>,
+{
+    /// Parses a map from a RON string. Since the default value isn't part of the RON, `V::default()` is used.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// #[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let map: EasyMap<&str, Point> = EasyMap::from_ron(r#"{
+    ///     "a": (x: 1, y: 2),
+    ///     "b": (x: 3, y: 4),
+    /// }"#).unwrap();
+    /// assert_eq!(map["a"], Point { x: 1, y: 2 });
+    /// assert_eq!(map["c"], Point::default());
+    /// ```
+    pub fn from_ron(s: &'de str) -> SpannedResult<EasyMap<K, V>> {
+        ron::from_str(s)
+    }
+}
+
+impl<'de, K: Eq + Hash + Deserialize<'de>> EasySet<K> {
+    /// Parses a set from a RON string.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasySet};
+    ///
+    /// let set = EasySet::from_ron("[(1, 2), (3, 4)]").unwrap();
+    /// assert_eq!(set, set!{(1, 2), (3, 4)});
+    /// ```
+    pub fn from_ron(s: &'de str) -> SpannedResult<EasySet<K>> {
+        ron::from_str(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{map, set};
+
+    #[test]
+    fn map_from_ron() {
+        let map = EasyMap::from_ron("{'a': [1, 2], 'b': []}").unwrap();
+        assert_eq!(map, map! {'a' => vec![1, 2], 'b' => vec![]});
+
+        let map = EasyMap::<String, EasySet<u8>>::from_ron(r#"{"foo": [1, 1, 2]}"#).unwrap();
+        assert_eq!(map["foo".to_string()], set! {1, 2});
+
+        assert!(EasyMap::<u8, u8>::from_ron("{1: 2").is_err());
+    }
+
+    #[test]
+    fn set_from_ron() {
+        let set = EasySet::from_ron(r#"["foo", "bar"]"#).unwrap();
+        assert_eq!(set, set! {"foo", "bar"});
+
+        assert!(EasySet::<u8>::from_ron("[1, 256]").is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{EasyMap, EasySet};
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::Rng;
+use std::hash::Hash;
+
+impl<K: Eq + Hash> EasySet<K> {
+    /// Randomly chooses `k` items from an iterator, using reservoir sampling so the iterator is only read once and
+    /// never collected. Every item has the same chance of being chosen. If the iterator has `k` items or fewer, they're
+    /// all chosen.
+    ///
+    /// The items are chosen by their position in the iterator, so if it yields duplicates the set may end up with
+    /// fewer than `k` items.
+    ///
+    /// ```rust
+    /// use easy_collections::EasySet;
+    ///
+    /// let sample = EasySet::sample_from(0..1_000_000, 3, &mut rand::rng());
+    /// assert_eq!(sample.len(), 3);
+    /// assert!(sample.iter().all(|n| *n < 1_000_000));
+    /// ```
+    pub fn sample_from<I, R>(iter: I, k: usize, rng: &mut R) -> EasySet<K>
+    where
+        I: IntoIterator<Item = K>,
+        R: Rng + ?Sized,
+    {
+        let mut reservoir = Vec::with_capacity(k);
+        for (i, item) in iter.into_iter().enumerate() {
+            if i < k {
+                reservoir.push(item);
+            } else {
+                let j = rng.random_range(0..=i);
+                if j < k {
+                    reservoir[j] = item;
+                }
+            }
+        }
+
+        reservoir.into_iter().collect()
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> EasyMap<K, V> {
+    /// Returns a copy of `n` randomly chosen entries, with the same default value as this map. If the map has `n`
+    /// entries or fewer, they're all chosen.
+    ///
+    /// ```rust
+    /// use easy_collections::EasyMap;
+    ///
+    /// let map = (0..100).map(|n| (n, n * n)).collect::<EasyMap<_, _>>();
+    /// let sample = map.sample_entries(5, &mut rand::rng());
+    /// assert_eq!(sample.len(), 5);
+    /// assert!(sample.iter().all(|(k, v)| k * k == *v));
+    /// ```
+    pub fn sample_entries<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> EasyMap<K, V> {
+        let mut sample = self.new_like();
+        for (k, v) in self.inner.iter().choose_multiple(rng, n) {
+            sample.insert(k.clone(), v.clone());
+        }
+
+        sample
+    }
+
+    /// Returns the entries of the map in a random order.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let map = map!{"a" => 1, "b" => 2, "c" => 3};
+    /// let entries = map.shuffled_entries(&mut rand::rng());
+    /// assert_eq!(entries.len(), 3);
+    /// ```
+    pub fn shuffled_entries<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<(&K, &V)> {
+        let mut entries = self.inner.iter().collect::<Vec<_>>();
+        entries.shuffle(rng);
+        entries
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{map, set};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn sample_from() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(EasySet::sample_from(1..=3, 5, &mut rng), set! {1, 2, 3});
+        assert_eq!(EasySet::sample_from(1..=3, 3, &mut rng), set! {1, 2, 3});
+        assert!(EasySet::sample_from(1..=3, 0, &mut rng).is_empty());
+
+        // every item should be chosen roughly as often as the others
+        let mut counts = map! {};
+        for _ in 0..2000 {
+            for n in EasySet::sample_from(0..10, 2, &mut rng) {
+                counts[n] += 1;
+            }
+        }
+        for n in 0..10 {
+            assert!(
+                (300..500).contains(&counts[n]),
+                "{} was chosen {} times",
+                n,
+                counts[n]
+            );
+        }
+    }
+
+    #[test]
+    fn sample_entries() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let map = map! {-1; 'a' => 1, 'b' => 2, 'c' => 3};
+        assert_eq!(map.sample_entries(10, &mut rng), map);
+        assert!(map.sample_entries(0, &mut rng).is_empty());
+
+        let sample = map.sample_entries(2, &mut rng);
+        assert_eq!(sample.len(), 2);
+        assert_eq!(sample['z'], -1);
+        assert!(sample.iter().all(|(k, v)| map[*k] == *v));
+    }
+
+    #[test]
+    fn shuffled_entries() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let map = (0..20).map(|n| (n, n)).collect::<crate::EasyMap<_, _>>();
+        let mut orders = set! {};
+        for _ in 0..5 {
+            let entries = map.shuffled_entries(&mut rng);
+            assert_eq!(entries.len(), 20);
+            orders.insert(entries.iter().map(|(k, _)| **k).collect::<Vec<_>>());
+        }
+        assert!(orders.len() > 1);
+
+        let mut sorted = map.shuffled_entries(&mut rng);
+        sorted.sort();
+        assert_eq!(
+            sorted,
+            map.iter()
+                .collect::<std::collections::BTreeMap<_, _>>()
+                .into_iter()
+                .collect::<Vec<_>>()
+        );
+    }
+}
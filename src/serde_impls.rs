@@ -0,0 +1,61 @@
+use crate::{EasyMap, EasySet};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+// NOTE: the default value of an `EasyMap` isn't serialized, so `V::default()` is used when deserializing
+impl<K: Eq + Hash + Serialize, V: Serialize> Serialize for EasyMap<K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for EasyMap<K, V>
+where
+    K: Eq + Hash + Deserialize<'de>,
+    V: Default + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(EasyMap::from(HashMap::deserialize(deserializer)?))
+    }
+}
+
+impl<K: Eq + Hash + Serialize> Serialize for EasySet<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de, K: Eq + Hash + Deserialize<'de>> Deserialize<'de> for EasySet<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(HashSet::deserialize(deserializer)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{map, set};
+
+    #[test]
+    fn map() {
+        let map = map! {42; "foo" => 1};
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"foo":1}"#);
+
+        let map: EasyMap<String, u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["foo".to_string()], 1);
+        assert_eq!(map["bar".to_string()], 0);
+    }
+
+    #[test]
+    fn set() {
+        let set = set! {1};
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, "[1]");
+
+        let set: EasySet<u32> = serde_json::from_str("[1, 2, 2]").unwrap();
+        assert_eq!(set, set! {1, 2});
+    }
+}
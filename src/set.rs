@@ -1,14 +1,16 @@
 use std::{
+    borrow::Borrow,
     cmp::{Ord, Ordering, PartialOrd},
-    collections::HashSet,
-    hash::Hash,
+    collections::{hash_set::Drain, HashSet, VecDeque},
+    hash::{BuildHasher, Hash},
     iter::FromIterator,
     ops::{
-        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Sub,
-        SubAssign,
+        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut,
+        RangeInclusive, Sub, SubAssign,
     },
 };
 
+use crate::EasyMap;
 use paste::paste;
 
 #[macro_export]
@@ -17,7 +19,7 @@ macro_rules! set {
         $crate::EasySet::new()
     };
     {$($key:expr$(,)?)*} => {{
-        let mut set = set!{};
+        let mut set = $crate::set!{};
         $(set.insert($key);)*
         set
     }};
@@ -49,7 +51,7 @@ macro_rules! set {
 /// assert!(a == a);
 /// ```
 ///
-/// You can also perform the same operations on types that implement `Into<EasySet<K>>`, for example:
+/// You can also perform the same operations on the other types that implement `SetOperand`, for example:
 /// ```rust
 /// use easy_collections::set;
 ///
@@ -58,9 +60,21 @@ macro_rules! set {
 /// assert_eq!(set!{'b', 'a', 'r'} & String::from("baz"), set!{'b', 'a'});
 /// assert_eq!(set!{"hello"} & std::collections::HashSet::new(), set!{});
 /// ```
+///
+/// Borrowed `EasySet`s and `HashSet`s are used directly, without being cloned:
+/// ```rust
+/// use easy_collections::set;
+/// use std::collections::HashSet;
+///
+/// let a = set!{1, 2, 3};
+/// let b = set!{3, 4, 5};
+/// let c: HashSet<_> = vec![1, 5].into_iter().collect();
+/// assert_eq!(&a & &b, set!{3});
+/// assert_eq!(&a | &c, set!{1, 2, 3, 5});
+/// ```
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct EasySet<K: Eq + Hash> {
-    inner: HashSet<K>,
+    pub(crate) inner: HashSet<K>,
 }
 
 impl<K: Eq + Hash> EasySet<K> {
@@ -96,6 +110,150 @@ impl<K: Eq + Hash> EasySet<K> {
         self.inner.remove(k)
     }
 
+    /// Inserts every item from `iter` into the set, the same as Python's `set.update`.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let mut set = set!{1, 2};
+    /// set.update(vec![2, 3]);
+    /// set.update(4..6);
+    /// assert_eq!(set, set!{1, 2, 3, 4, 5});
+    /// ```
+    pub fn update(&mut self, iter: impl IntoIterator<Item = K>) {
+        self.inner.extend(iter);
+    }
+
+    /// Removes every item which isn't in `other`, the same as Python's `set.intersection_update`.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let mut set = set!{1, 2, 3};
+    /// set.intersection_update(vec![2, 3, 4]);
+    /// assert_eq!(set, set!{2, 3});
+    /// set.intersection_update(&set!{3});
+    /// assert_eq!(set, set!{3});
+    /// ```
+    pub fn intersection_update<Q>(&mut self, other: impl IntoIterator<Item = Q>)
+    where
+        Q: Borrow<K> + Eq + Hash,
+    {
+        let other = other.into_iter().collect::<HashSet<Q>>();
+        self.inner.retain(|k| other.contains(k));
+    }
+
+    /// Removes every item which is in `other`, the same as Python's `set.difference_update`.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let mut set = set!{1, 2, 3};
+    /// set.difference_update(vec![2, 4]);
+    /// assert_eq!(set, set!{1, 3});
+    /// set.difference_update(&set!{3});
+    /// assert_eq!(set, set!{1});
+    /// ```
+    pub fn difference_update<Q: Borrow<K>>(&mut self, other: impl IntoIterator<Item = Q>) {
+        for k in other {
+            self.inner.remove(k.borrow());
+        }
+    }
+
+    /// Consumes the set, splitting it into the items which match `pred` and the items which don't.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let (even, odd) = set!{1, 2, 3, 4, 5}.partition(|n| n % 2 == 0);
+    /// assert_eq!(even, set!{2, 4});
+    /// assert_eq!(odd, set!{1, 3, 5});
+    /// ```
+    pub fn partition(self, pred: impl FnMut(&K) -> bool) -> (EasySet<K>, EasySet<K>) {
+        self.inner.into_iter().partition(pred)
+    }
+
+    /// Same as `HashSet::drain`. The set keeps its allocated capacity.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let mut set = set!{1};
+    /// assert_eq!(set.drain().collect::<Vec<_>>(), vec![1]);
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, K> {
+        self.inner.drain()
+    }
+
+    /// Moves every item of the set into `other`. The set keeps its allocated capacity, so it can be reused.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let mut batch = set!{1, 2};
+    /// let mut seen = set!{2, 3};
+    /// batch.drain_into(&mut seen);
+    /// assert!(batch.is_empty());
+    /// assert_eq!(seen, set!{1, 2, 3});
+    /// ```
+    pub fn drain_into(&mut self, other: &mut EasySet<K>) {
+        other.inner.extend(self.inner.drain());
+    }
+
+    /// Consumes the set, creating a map with an entry for each item, with values from `f`.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let dist = set!{"a", "b"}.into_map_with(|_| u32::MAX);
+    /// assert_eq!(dist["a"], u32::MAX);
+    /// assert_eq!(dist["b"], u32::MAX);
+    /// assert_eq!(dist["c"], 0);
+    /// ```
+    pub fn into_map_with<V, F>(self, mut f: F) -> EasyMap<K, V>
+    where
+        V: Default,
+        F: FnMut(&K) -> V,
+    {
+        self.inner
+            .into_iter()
+            .map(|k| {
+                let v = f(&k);
+                (k, v)
+            })
+            .collect()
+    }
+
+    /// Creates a set from an iterator of fallible items, stopping at the first error and returning it. This is the
+    /// same as collecting into a `Result<EasySet<K>, E>`, which also works:
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasySet};
+    ///
+    /// let input = "3\n1\n3";
+    /// let set = EasySet::try_from_iter(input.lines().map(str::parse::<u8>)).unwrap();
+    /// assert_eq!(set, set!{1, 3});
+    ///
+    /// let set = "3\nx".lines().map(str::parse::<u8>).collect::<Result<EasySet<_>, _>>();
+    /// assert!(set.is_err());
+    /// ```
+    pub fn try_from_iter<E>(iter: impl IntoIterator<Item = Result<K, E>>) -> Result<EasySet<K>, E> {
+        iter.into_iter().collect()
+    }
+
+    /// Consumes the set, converting each item into `K2` with `From`.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasySet};
+    ///
+    /// let set: EasySet<u64> = set!{1_u8, 2, 3}.map_into();
+    /// assert_eq!(set, set!{1, 2, 3});
+    /// ```
+    pub fn map_into<K2: Eq + Hash + From<K>>(self) -> EasySet<K2> {
+        self.inner.into_iter().map(K2::from).collect()
+    }
+
     /// Inserts the key into the set _if it wasn't in the set_. If it was in the set _it is removed_.
     ///
     /// ```rust
@@ -117,6 +275,312 @@ impl<K: Eq + Hash> EasySet<K> {
 
         contained_key
     }
+
+    /// Returns the set with `k` inserted. Along with `EasySet::removed` and `EasySet::toggled`, this lets you build
+    /// sets inside expressions, such as folds.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasySet};
+    ///
+    /// let evens = (0..10).fold(EasySet::new(), |acc, n| if n % 2 == 0 { acc.inserted(n) } else { acc });
+    /// assert_eq!(evens, set!{0, 2, 4, 6, 8});
+    /// ```
+    pub fn inserted(mut self, k: K) -> EasySet<K> {
+        self.insert(k);
+        self
+    }
+
+    /// Returns the set with `k` removed.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// assert_eq!(set!{1, 2, 3}.removed(&2).inserted(4), set!{1, 3, 4});
+    /// ```
+    pub fn removed(mut self, k: &K) -> EasySet<K> {
+        self.remove(k);
+        self
+    }
+
+    /// Returns the set with `k` toggled, see `EasySet::toggle`.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// assert_eq!(set!{1, 2}.toggled(2).toggled(3), set!{1, 3});
+    /// ```
+    pub fn toggled(mut self, k: K) -> EasySet<K> {
+        self.toggle(k);
+        self
+    }
+}
+
+impl<K: Eq + Hash + Clone> EasySet<K> {
+    /// Returns the items which are in both sets, the same as `&self & other`.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let a = set!{1, 2, 3};
+    /// assert_eq!(a.intersect(vec![2, 3, 4]), set!{2, 3});
+    /// assert_eq!(a.union_with(set!{4}), set!{1, 2, 3, 4});
+    /// assert_eq!(a.minus(vec![1]), set!{2, 3});
+    /// assert_eq!(a.sym_diff(set!{3, 4}), set!{1, 2, 4});
+    ///
+    /// // borrowed sets aren't cloned
+    /// let b = set!{3, 4};
+    /// assert_eq!(a.intersect(&b), set!{3});
+    /// ```
+    pub fn intersect(&self, other: impl SetOperand<K>) -> EasySet<K> {
+        self & other
+    }
+
+    /// Returns the items which are in either set, the same as `&self | other`.
+    pub fn union_with(&self, other: impl SetOperand<K>) -> EasySet<K> {
+        self | other
+    }
+
+    /// Returns the items which are in this set but not in `other`, the same as `&self - other`.
+    pub fn minus(&self, other: impl SetOperand<K>) -> EasySet<K> {
+        self - other
+    }
+
+    /// Returns the items which are in exactly one of the sets, the same as `&self ^ other`.
+    pub fn sym_diff(&self, other: impl SetOperand<K>) -> EasySet<K> {
+        self ^ other
+    }
+
+    /// Removes every item which is in `other` and inserts every item of `other` which wasn't in the set, the same as
+    /// Python's `set.symmetric_difference_update`.
+    ///
+    /// ```rust
+    /// use easy_collections::set;
+    ///
+    /// let mut set = set!{1, 2, 3};
+    /// set.symmetric_difference_update(vec![3, 4]);
+    /// assert_eq!(set, set!{1, 2, 4});
+    /// set.symmetric_difference_update(&set!{1, 5});
+    /// assert_eq!(set, set!{2, 4, 5});
+    /// ```
+    pub fn symmetric_difference_update<Q>(&mut self, other: impl IntoIterator<Item = Q>)
+    where
+        Q: Borrow<K> + Eq + Hash,
+    {
+        for k in other.into_iter().collect::<HashSet<Q>>() {
+            let k = k.borrow();
+            if !self.inner.remove(k) {
+                self.inner.insert(k.clone());
+            }
+        }
+    }
+
+    /// Returns the items which are in an odd number of `sets`, which is the symmetric difference of all of them.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasySet};
+    ///
+    /// let sets = vec![set!{1, 2, 3}, set!{2, 3, 4}, set!{3, 4, 5}];
+    /// assert_eq!(EasySet::symmetric_difference_all(&sets), set!{1, 3, 5});
+    /// assert_eq!(EasySet::appearing_exactly_once(&sets), set!{1, 5});
+    /// ```
+    pub fn symmetric_difference_all<S, Q>(sets: impl IntoIterator<Item = S>) -> EasySet<K>
+    where
+        S: IntoIterator<Item = Q>,
+        Q: Borrow<K> + Eq + Hash,
+    {
+        let mut set = EasySet::new();
+        for other in sets {
+            set.symmetric_difference_update(other);
+        }
+
+        set
+    }
+
+    /// Returns the items which are in exactly one of `sets`.
+    pub fn appearing_exactly_once<S, Q>(sets: impl IntoIterator<Item = S>) -> EasySet<K>
+    where
+        S: IntoIterator<Item = Q>,
+        Q: Borrow<K> + Eq + Hash,
+    {
+        let mut counts: EasyMap<K, usize> = EasyMap::new();
+        for other in sets {
+            for k in other.into_iter().collect::<HashSet<Q>>() {
+                counts[k.borrow().clone()] += 1;
+            }
+        }
+
+        counts
+            .into_iter()
+            .filter(|(_, n)| *n == 1)
+            .map(|(k, _)| k)
+            .collect()
+    }
+
+    /// Computes the set of everything reachable from `seed` by repeatedly following `neighbours`.
+    /// The seed values themselves are included in the result.
+    ///
+    /// ```rust
+    /// use easy_collections::{map, set, EasySet};
+    ///
+    /// let edges = map!{1 => vec![2, 3], 2 => vec![4], 5 => vec![6]};
+    /// let reachable = EasySet::closure([1], |n| edges[*n].clone());
+    /// assert_eq!(reachable, set!{1, 2, 3, 4});
+    /// ```
+    pub fn closure<I, F>(seed: impl IntoIterator<Item = K>, mut neighbours: F) -> EasySet<K>
+    where
+        I: IntoIterator<Item = K>,
+        F: FnMut(&K) -> I,
+    {
+        let mut set = EasySet::new();
+        let mut queue = VecDeque::new();
+        for k in seed {
+            if set.insert(k.clone()) {
+                queue.push_back(k);
+            }
+        }
+
+        while let Some(k) = queue.pop_front() {
+            for n in neighbours(&k) {
+                if set.insert(n.clone()) {
+                    queue.push_back(n);
+                }
+            }
+        }
+
+        set
+    }
+}
+
+impl EasySet<char> {
+    /// Returns a set of the characters in the range.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasySet};
+    ///
+    /// assert_eq!(EasySet::from_char_range('a'..='c'), set!{'a', 'b', 'c'});
+    /// ```
+    pub fn from_char_range(range: RangeInclusive<char>) -> EasySet<char> {
+        range.collect()
+    }
+
+    /// Returns a set of the characters in the string.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasySet};
+    ///
+    /// assert_eq!(EasySet::from_str_chars("+-*/"), set!{'+', '-', '*', '/'});
+    /// ```
+    pub fn from_str_chars(s: &str) -> EasySet<char> {
+        s.chars().collect()
+    }
+
+    /// Returns a set of the ASCII lowercase letters, `a` to `z`.
+    ///
+    /// ```rust
+    /// use easy_collections::EasySet;
+    ///
+    /// let unused = EasySet::ascii_lowercase() - String::from("the quick brown fox");
+    /// assert_eq!(unused.len(), 11);
+    /// ```
+    pub fn ascii_lowercase() -> EasySet<char> {
+        EasySet::from_char_range('a'..='z')
+    }
+
+    /// Returns a set of the ASCII uppercase letters, `A` to `Z`.
+    pub fn ascii_uppercase() -> EasySet<char> {
+        EasySet::from_char_range('A'..='Z')
+    }
+
+    /// Returns a set of the ASCII digits, `0` to `9`.
+    pub fn ascii_digits() -> EasySet<char> {
+        EasySet::from_char_range('0'..='9')
+    }
+}
+
+impl EasySet<String> {
+    /// Returns the set of every window of `n` characters in `text`. If `text` is shorter than `n` characters, the set
+    /// is just `text` itself (unless it's empty).
+    ///
+    /// Panics if `n` is `0`.
+    ///
+    /// ```rust
+    /// use easy_collections::{set, EasySet};
+    ///
+    /// let shingles = EasySet::shingles("banana", 3);
+    /// assert_eq!(shingles, set!{"ban".to_string(), "ana".to_string(), "nan".to_string()});
+    /// ```
+    ///
+    /// These work well with `EasySet::minhash`, for finding similar pieces of text:
+    /// ```rust
+    /// use easy_collections::EasySet;
+    ///
+    /// let a = EasySet::shingles("the quick brown fox jumps over the lazy dog", 4).minhash(128);
+    /// let b = EasySet::shingles("the quick brown fox leaps over the lazy dog", 4).minhash(128);
+    /// let c = EasySet::shingles("lorem ipsum dolor sit amet", 4).minhash(128);
+    /// assert!(a.estimated_jaccard(&b) > a.estimated_jaccard(&c));
+    /// ```
+    pub fn shingles(text: &str, n: usize) -> EasySet<String> {
+        let chars = text.chars().collect::<Vec<_>>();
+        EasySet::windows_of(&chars, n, |window| window.iter().collect())
+    }
+
+    /// Returns the set of every window of `n` words in `text`, with the words separated by single spaces. If `text`
+    /// has fewer than `n` words, the set has one shingle of all of its words.
+    ///
+    /// Panics if `n` is `0`.
+    ///
+    /// ```rust
+    /// use easy_collections::EasySet;
+    ///
+    /// let shingles = EasySet::word_shingles("to be or not to be", 2);
+    /// assert_eq!(shingles.len(), 4);
+    /// assert!(shingles.contains(&"not to".to_string()));
+    /// ```
+    pub fn word_shingles(text: &str, n: usize) -> EasySet<String> {
+        let words = text.split_whitespace().collect::<Vec<_>>();
+        EasySet::windows_of(&words, n, |window| window.join(" "))
+    }
+
+    /// Returns the set of every window of `n` items, or all of the items if there are fewer than `n`.
+    ///
+    /// Panics if `n` is `0`.
+    fn windows_of<T>(items: &[T], n: usize, f: impl FnMut(&[T]) -> String) -> EasySet<String> {
+        assert!(n > 0, "shingles must be at least 1 long");
+        if items.is_empty() {
+            return EasySet::new();
+        }
+
+        items.windows(n.min(items.len())).map(f).collect()
+    }
+}
+
+/// Returns, for every item in `sets`, the indices of the sets which contain it.
+///
+/// ```rust
+/// use easy_collections::{membership, set};
+///
+/// let sets = [set!{"a", "b"}, set!{"b", "c"}, set!{"b"}];
+/// let membership = membership(&sets);
+/// assert_eq!(membership["a"], set!{0});
+/// assert_eq!(membership["b"], set!{0, 1, 2});
+/// assert_eq!(membership["c"], set!{1});
+/// assert!(membership["d"].is_empty());
+/// ```
+pub fn membership<K: Eq + Hash + Clone>(sets: &[EasySet<K>]) -> EasyMap<K, EasySet<usize>> {
+    let mut membership: EasyMap<K, EasySet<usize>> = EasyMap::new();
+    for (i, set) in sets.iter().enumerate() {
+        for k in set {
+            membership[k.clone()].insert(i);
+        }
+    }
+
+    membership
+}
+
+impl<K: Eq + Hash> Default for EasySet<K> {
+    fn default() -> Self {
+        EasySet::new()
+    }
 }
 
 impl<K: Eq + Hash> From<Vec<K>> for EasySet<K> {
@@ -127,7 +591,7 @@ impl<K: Eq + Hash> From<Vec<K>> for EasySet<K> {
 
 impl<K: Eq + Hash + Clone> From<&Vec<K>> for EasySet<K> {
     fn from(v: &Vec<K>) -> Self {
-        v.into_iter().cloned().collect()
+        v.iter().cloned().collect()
     }
 }
 
@@ -154,6 +618,12 @@ impl<K: Eq + Hash> FromIterator<K> for EasySet<K> {
     }
 }
 
+impl<K: Eq + Hash> Extend<K> for EasySet<K> {
+    fn extend<T: IntoIterator<Item = K>>(&mut self, iter: T) {
+        self.inner.extend(iter);
+    }
+}
+
 impl<K: Eq + Hash> IntoIterator for EasySet<K> {
     type Item = K;
     type IntoIter = std::collections::hash_set::IntoIter<Self::Item>;
@@ -163,6 +633,15 @@ impl<K: Eq + Hash> IntoIterator for EasySet<K> {
     }
 }
 
+impl<'a, K: Eq + Hash> IntoIterator for &'a EasySet<K> {
+    type Item = &'a K;
+    type IntoIter = std::collections::hash_set::Iter<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
 impl<K: Eq + Hash> Deref for EasySet<K> {
     type Target = HashSet<K>;
     fn deref(&self) -> &Self::Target {
@@ -226,37 +705,213 @@ impl<K: Eq + Hash + Clone> From<&EasySet<K>> for HashSet<K> {
     }
 }
 
-// TODO: once we have specialisation: https://github.com/rust-lang/rust/issues/31844
-// then we can impl much more performant variants of these traits
+pub(crate) mod private {
+    use super::*;
+
+    pub trait Sealed {}
+
+    /// A set which the right hand side of an operator is used as, either borrowed or converted.
+    pub trait OperandSet<K> {
+        fn size(&self) -> usize;
+        fn has(&self, k: &K) -> bool;
+        fn items<'a>(&'a self) -> impl Iterator<Item = &'a K>
+        where
+            K: 'a;
+    }
+
+    impl<K: Eq + Hash> OperandSet<K> for EasySet<K> {
+        fn size(&self) -> usize {
+            self.len()
+        }
+        fn has(&self, k: &K) -> bool {
+            self.contains(k)
+        }
+        fn items<'a>(&'a self) -> impl Iterator<Item = &'a K>
+        where
+            K: 'a,
+        {
+            self.iter()
+        }
+    }
+
+    impl<K: Eq + Hash, S: BuildHasher> OperandSet<K> for &HashSet<K, S> {
+        fn size(&self) -> usize {
+            self.len()
+        }
+        fn has(&self, k: &K) -> bool {
+            self.contains(k)
+        }
+        fn items<'a>(&'a self) -> impl Iterator<Item = &'a K>
+        where
+            K: 'a,
+        {
+            self.iter()
+        }
+    }
+}
+
+use self::private::OperandSet;
+
+/// The types which can be the right hand side of the set operators, and of methods like `EasySet::intersect`.
+/// Borrowed `EasySet`s and `HashSet`s are used directly, without being cloned, and the other types are converted into
+/// an `EasySet` first.
+///
+/// This trait is sealed, so it can't be implemented outside of this crate.
+pub trait SetOperand<K>: private::Sealed {
+    #[doc(hidden)]
+    type Set: OperandSet<K>;
+    #[doc(hidden)]
+    fn into_set(self) -> Self::Set;
+}
+
+impl<K: Eq + Hash> private::Sealed for EasySet<K> {}
+impl<K: Eq + Hash> SetOperand<K> for EasySet<K> {
+    type Set = EasySet<K>;
+    fn into_set(self) -> Self::Set {
+        self
+    }
+}
+
+impl<K: Eq + Hash> private::Sealed for &EasySet<K> {}
+impl<'a, K: Eq + Hash> SetOperand<K> for &'a EasySet<K> {
+    type Set = &'a HashSet<K>;
+    fn into_set(self) -> Self::Set {
+        &self.inner
+    }
+}
+
+impl<K: Eq + Hash> private::Sealed for HashSet<K> {}
+impl<K: Eq + Hash> SetOperand<K> for HashSet<K> {
+    type Set = EasySet<K>;
+    fn into_set(self) -> Self::Set {
+        self.into()
+    }
+}
+
+impl<K: Eq + Hash, S: BuildHasher> private::Sealed for &HashSet<K, S> {}
+impl<'a, K: Eq + Hash, S: BuildHasher> SetOperand<K> for &'a HashSet<K, S> {
+    type Set = &'a HashSet<K, S>;
+    fn into_set(self) -> Self::Set {
+        self
+    }
+}
+
+impl<K: Eq + Hash> private::Sealed for Vec<K> {}
+impl<K: Eq + Hash> SetOperand<K> for Vec<K> {
+    type Set = EasySet<K>;
+    fn into_set(self) -> Self::Set {
+        self.into()
+    }
+}
+
+impl<K: Eq + Hash + Clone> private::Sealed for &Vec<K> {}
+impl<K: Eq + Hash + Clone> SetOperand<K> for &Vec<K> {
+    type Set = EasySet<K>;
+    fn into_set(self) -> Self::Set {
+        self.into()
+    }
+}
+
+impl<K: Eq + Hash + Clone> private::Sealed for &[K] {}
+impl<K: Eq + Hash + Clone> SetOperand<K> for &[K] {
+    type Set = EasySet<K>;
+    fn into_set(self) -> Self::Set {
+        self.into()
+    }
+}
+
+impl private::Sealed for String {}
+impl SetOperand<char> for String {
+    type Set = EasySet<char>;
+    fn into_set(self) -> Self::Set {
+        self.into()
+    }
+}
+
+impl<K: Eq + Hash + Clone> EasySet<K> {
+    /// Returns the items which are in both sets, looking up the items of the smaller set in the larger one.
+    fn intersect_set(&self, other: &impl OperandSet<K>) -> EasySet<K> {
+        if self.len() <= other.size() {
+            self.iter().filter(|k| other.has(k)).cloned().collect()
+        } else {
+            other
+                .items()
+                .filter(|k| self.contains(*k))
+                .cloned()
+                .collect()
+        }
+    }
+
+    /// Returns the items which are in this set but not in `other`.
+    fn minus_set(&self, other: &impl OperandSet<K>) -> EasySet<K> {
+        self.iter().filter(|k| !other.has(k)).cloned().collect()
+    }
+
+    /// Returns the items which are in exactly one of the sets.
+    fn sym_diff_set(&self, other: &impl OperandSet<K>) -> EasySet<K> {
+        let ours = self.iter().filter(|k| !other.has(k));
+        let theirs = other.items().filter(|k| !self.contains(*k));
+        ours.chain(theirs).cloned().collect()
+    }
+}
+
 macro_rules! impl_bit_op {
-    ($trait:ty, $method:ident, $set_op:ident) => {
+    ($trait:ident, $method:ident, |$set:ident, $other:ident| $borrowed:expr, |$this:ident, $rhs:ident| $assign:expr) => {
         paste! {
-            impl<K: Eq + Hash + Clone, T: Into<EasySet<K>>> $trait<T> for &EasySet<K> {
+            impl<K: Eq + Hash + Clone, T: SetOperand<K>> $trait<T> for &EasySet<K> {
                 type Output = EasySet<K>;
                 fn $method(self, rhs: T) -> Self::Output {
-                    self.inner.$set_op(&rhs.into()).cloned().collect()
+                    let ($set, $other) = (self, &rhs.into_set());
+                    $borrowed
                 }
             }
-            impl<K: Eq + Hash + Clone, T: Into<EasySet<K>>> $trait<T> for EasySet<K> {
+            impl<K: Eq + Hash + Clone, T: SetOperand<K>> $trait<T> for EasySet<K> {
                 type Output = Self;
-                fn $method(self, rhs: T) -> Self::Output {
-                    self.inner.$set_op(&rhs.into()).cloned().collect()
+                fn $method(mut self, rhs: T) -> Self::Output {
+                    self.[<$method _assign>](rhs);
+                    self
                 }
             }
-            // assign
-            impl<K: Eq + Hash + Clone, T: Into<EasySet<K>>> [<$trait Assign>]<T> for EasySet<K> {
+            impl<K: Eq + Hash + Clone, T: SetOperand<K>> [<$trait Assign>]<T> for EasySet<K> {
                 fn [<$method _assign>](&mut self, rhs: T) {
-                    *self = self.inner.$set_op(&rhs.into()).cloned().collect()
+                    let ($this, $rhs) = (self, &rhs.into_set());
+                    $assign
                 }
             }
         }
     };
 }
 
-impl_bit_op!(BitAnd, bitand, intersection);
-impl_bit_op!(BitOr, bitor, union);
-impl_bit_op!(BitXor, bitxor, symmetric_difference);
-impl_bit_op!(Sub, sub, difference);
+impl_bit_op! {
+    BitAnd, bitand,
+    |set, other| set.intersect_set(other),
+    |this, rhs| this.inner.retain(|k| rhs.has(k))
+}
+impl_bit_op! {
+    BitOr, bitor,
+    |set, other| {
+        let mut set = set.clone();
+        set.inner.extend(other.items().cloned());
+        set
+    },
+    |this, rhs| this.inner.extend(rhs.items().cloned())
+}
+impl_bit_op! {
+    BitXor, bitxor,
+    |set, other| set.sym_diff_set(other),
+    |this, rhs| {
+        for k in rhs.items() {
+            if !this.inner.remove(k) {
+                this.inner.insert(k.clone());
+            }
+        }
+    }
+}
+impl_bit_op! {
+    Sub, sub,
+    |set, other| set.minus_set(other),
+    |this, rhs| this.inner.retain(|k| !rhs.has(k))
+}
 
 #[cfg(test)]
 mod test {
@@ -265,21 +920,21 @@ mod test {
     #[test]
     fn macros() {
         let set: EasySet<char> = set!();
-        assert_eq!(set.contains(&'a'), false);
-        assert_eq!(set.contains(&'b'), false);
-        assert_eq!(set.contains(&'c'), false);
+        assert!(!set.contains(&'a'));
+        assert!(!set.contains(&'b'));
+        assert!(!set.contains(&'c'));
 
         // without trailing comma
         let set = set! {'a', 'b', 'c'};
-        assert_eq!(set.contains(&'a'), true);
-        assert_eq!(set.contains(&'b'), true);
-        assert_eq!(set.contains(&'c'), true);
+        assert!(set.contains(&'a'));
+        assert!(set.contains(&'b'));
+        assert!(set.contains(&'c'));
 
         // with trailing comma
         let set = set! {'d', 'e', 'f',};
-        assert_eq!(set.contains(&'d'), true);
-        assert_eq!(set.contains(&'e'), true);
-        assert_eq!(set.contains(&'f'), true);
+        assert!(set.contains(&'d'));
+        assert!(set.contains(&'e'));
+        assert!(set.contains(&'f'));
     }
 
     #[test]
@@ -331,7 +986,7 @@ mod test {
 
     #[test]
     fn from_iter() {
-        let v = vec!['i', 't', 'e', 'r'];
+        let v = ['i', 't', 'e', 'r'];
         let s = v.iter().collect::<EasySet<_>>();
         assert_eq!(s, set! {&'i', &'t', &'e', &'r'});
     }
@@ -383,4 +1038,308 @@ mod test {
     test_op!(ops_bitor, |, |=, [1, 2, 3, 4, 5]);
     test_op!(ops_bitxor, ^, ^=, [1, 2, 4, 5]);
     test_op!(ops_sub, -, -=, [1, 2]);
+
+    #[test]
+    fn drain() {
+        let mut set = set! {'a', 'b', 'c'};
+        let capacity = set.capacity();
+
+        let mut values = set.drain().collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values, &['a', 'b', 'c']);
+
+        assert!(set.is_empty());
+        assert_eq!(set.capacity(), capacity);
+    }
+
+    #[test]
+    fn closure() {
+        // cycles are only visited once
+        let set = EasySet::closure(vec![0], |n| vec![(n + 1) % 5]);
+        assert_eq!(set, set! {0, 1, 2, 3, 4});
+
+        // multiple seeds
+        let set = EasySet::closure(vec![10, 20], |n| {
+            if *n % 10 == 0 {
+                vec![n + 1]
+            } else {
+                vec![]
+            }
+        });
+        assert_eq!(set, set! {10, 11, 20, 21});
+
+        // no seeds
+        let set = EasySet::closure(Vec::<u8>::new(), |n| vec![*n]);
+        assert_eq!(set, set! {});
+    }
+
+    #[test]
+    fn update() {
+        let mut set = set! {'a'};
+        set.update("abc".chars());
+        set.update(set! {'d'});
+        set.update(None);
+        assert_eq!(set, set! {'a', 'b', 'c', 'd'});
+    }
+
+    #[test]
+    fn update_methods() {
+        let mut set = set! {1, 2, 3, 4};
+        set.intersection_update(vec![1, 2, 3, 5]);
+        assert_eq!(set, set! {1, 2, 3});
+        set.intersection_update([2, 3, 3].iter());
+        assert_eq!(set, set! {2, 3});
+
+        let mut set = set! {1, 2, 3, 4};
+        set.difference_update(vec![1, 1, 5]);
+        assert_eq!(set, set! {2, 3, 4});
+        set.difference_update(&set! {2});
+        assert_eq!(set, set! {3, 4});
+
+        // duplicates in the iterator should only toggle once
+        let mut set = set! {1, 2};
+        set.symmetric_difference_update(vec![2, 2, 3, 3]);
+        assert_eq!(set, set! {1, 3});
+        set.symmetric_difference_update([1, 1, 4].iter());
+        assert_eq!(set, set! {3, 4});
+    }
+
+    macro_rules! test_borrowed_op {
+        ($name:ident, $op:tt, $op_assign:tt, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let a = set! {1, 2, 3};
+                let b = set! {3, 4, 5};
+                let hash: HashSet<_> = b.iter().cloned().collect();
+                let expected = $expected.iter().cloned().collect::<EasySet<_>>();
+
+                assert_eq!(&a $op &b, expected);
+                assert_eq!(a.clone() $op &b, expected);
+                assert_eq!(&a $op &hash, expected);
+                assert_eq!(a.clone() $op &hash, expected);
+
+                let mut c = a.clone();
+                c $op_assign &b;
+                assert_eq!(c, expected);
+
+                let mut c = a.clone();
+                c $op_assign &hash;
+                assert_eq!(c, expected);
+
+                // the right hand side can still be used
+                assert_eq!(b, set! {3, 4, 5});
+            }
+        };
+    }
+
+    test_borrowed_op!(ops_bitand_borrowed, &, &=, [3]);
+    test_borrowed_op!(ops_bitor_borrowed, |, |=, [1, 2, 3, 4, 5]);
+    test_borrowed_op!(ops_bitxor_borrowed, ^, ^=, [1, 2, 4, 5]);
+    test_borrowed_op!(ops_sub_borrowed, -, -=, [1, 2]);
+
+    #[test]
+    fn partition() {
+        let (a, b) = set! {"foo", "bar", "baz"}.partition(|s| s.starts_with('b'));
+        assert_eq!(a, set! {"bar", "baz"});
+        assert_eq!(b, set! {"foo"});
+
+        let (a, b) = set! {1, 2}.partition(|_| false);
+        assert_eq!(a, set! {});
+        assert_eq!(b, set! {1, 2});
+    }
+
+    #[test]
+    fn into_map_with() {
+        let map = set! {1, 2, 3}.into_map_with(|n| n * 10);
+        assert_eq!(map, crate::map! {1 => 10, 2 => 20, 3 => 30});
+        assert_eq!(map[4], 0);
+        assert!(EasySet::<u8>::new().into_map_with(|_| 'x').is_empty());
+    }
+
+    #[test]
+    fn ops_other_hashers() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::BuildHasherDefault;
+
+        type OtherSet<K> = HashSet<K, BuildHasherDefault<DefaultHasher>>;
+        let other = [3, 4].iter().cloned().collect::<OtherSet<_>>();
+        let a = set! {1, 2, 3};
+
+        assert_eq!(&a & &other, set! {3});
+        assert_eq!(a.clone() | &other, set! {1, 2, 3, 4});
+        assert_eq!(&a ^ &other, set! {1, 2, 4});
+        assert_eq!(a.clone() - &other, set! {1, 2});
+
+        let mut b = a.clone();
+        b &= &other;
+        assert_eq!(b, set! {3});
+        b |= &other;
+        assert_eq!(b, set! {3, 4});
+    }
+
+    #[test]
+    fn fluent() {
+        let set = EasySet::new().inserted('a').inserted('b').inserted('a');
+        assert_eq!(set, set! {'a', 'b'});
+        assert_eq!(set.clone().removed(&'z'), set);
+        assert_eq!(set.clone().removed(&'a').removed(&'b'), set! {});
+        assert_eq!(set.toggled('a').toggled('c'), set! {'b', 'c'});
+
+        let seen = "abcab".chars().fold(set! {}, |acc, c| acc.toggled(c));
+        assert_eq!(seen, set! {'c'});
+    }
+
+    #[test]
+    fn char_classes() {
+        assert_eq!(EasySet::ascii_lowercase().len(), 26);
+        assert_eq!(EasySet::ascii_uppercase().len(), 26);
+        assert_eq!(
+            EasySet::ascii_digits(),
+            EasySet::from_str_chars("0123456789")
+        );
+        assert!(EasySet::ascii_lowercase()
+            .iter()
+            .all(|c| c.is_ascii_lowercase()));
+        assert!(EasySet::ascii_uppercase()
+            .iter()
+            .all(|c| c.is_ascii_uppercase()));
+        assert_eq!(
+            EasySet::ascii_lowercase() & EasySet::ascii_uppercase(),
+            set! {}
+        );
+        assert_eq!(EasySet::from_char_range('z'..='a'), set! {});
+        assert_eq!(EasySet::from_str_chars(""), set! {});
+        assert_eq!(EasySet::from_str_chars("aab"), set! {'a', 'b'});
+    }
+
+    #[test]
+    fn map_into() {
+        let set: EasySet<String> = set! {"a", "b"}.map_into();
+        assert!(set.contains(&String::from("a")));
+        assert_eq!(set.len(), 2);
+        let empty: EasySet<i64> = EasySet::<i32>::new().map_into();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn try_collect() {
+        let set = EasySet::try_from_iter(vec![Ok::<_, ()>(1), Ok(2), Ok(1)]);
+        assert_eq!(set, Ok(set! {1, 2}));
+        let set = vec![Ok(1), Err("bad"), Err("worse")]
+            .into_iter()
+            .collect::<Result<EasySet<u8>, _>>();
+        assert_eq!(set, Err("bad"));
+    }
+
+    #[test]
+    fn shingles() {
+        let s = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<EasySet<_>>();
+        assert_eq!(EasySet::shingles("abcd", 2), s(&["ab", "bc", "cd"]));
+        assert_eq!(EasySet::shingles("ab", 5), s(&["ab"]));
+        assert_eq!(EasySet::shingles("", 2), s(&[]));
+        assert_eq!(EasySet::shingles("héllo", 4), s(&["héll", "éllo"]));
+        assert_eq!(
+            EasySet::word_shingles("a b  c\nd", 3),
+            s(&["a b c", "b c d"])
+        );
+        assert_eq!(EasySet::word_shingles("one", 2), s(&["one"]));
+        assert_eq!(EasySet::word_shingles("two words", 3), s(&["two words"]));
+        assert_eq!(EasySet::word_shingles("  ", 1), s(&[]));
+    }
+
+    #[test]
+    #[should_panic(expected = "shingles must be at least 1 long")]
+    fn empty_shingles() {
+        EasySet::shingles("abc", 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "shingles must be at least 1 long")]
+    fn empty_word_shingles() {
+        EasySet::word_shingles("a b c", 0);
+    }
+
+    #[test]
+    fn drain_into() {
+        let mut set = set! {1, 2};
+        let capacity = set.capacity();
+        let mut other = set! {2, 3};
+        set.drain_into(&mut other);
+        assert!(set.is_empty());
+        assert_eq!(set.capacity(), capacity);
+        assert_eq!(other, set! {1, 2, 3});
+    }
+
+    #[test]
+    fn named_ops() {
+        let a = set! {1, 2, 3};
+        let b = set! {3, 4};
+        assert_eq!(a.intersect(b.clone()), &a & &b);
+        assert_eq!(a.union_with(b.clone()), &a | &b);
+        assert_eq!(a.minus(b.clone()), &a - &b);
+        assert_eq!(a.sym_diff(b.clone()), &a ^ &b);
+        assert_eq!(a.intersect(&b), &a & &b);
+        assert_eq!(a.union_with(&b), &a | &b);
+        assert_eq!(a.minus(&b), &a - &b);
+        assert_eq!(a.sym_diff(&b), &a ^ &b);
+        assert_eq!(a.intersect(vec![1]), set! {1});
+        assert_eq!(a.minus(HashSet::from([1, 2, 3])), set! {});
+        assert_eq!(a, set! {1, 2, 3});
+    }
+
+    #[test]
+    fn many_sets() {
+        let sets = vec![set! {1, 2}, set! {2, 3}, set! {2, 4}];
+        assert_eq!(EasySet::symmetric_difference_all(&sets), set! {1, 2, 3, 4});
+        assert_eq!(EasySet::appearing_exactly_once(&sets), set! {1, 3, 4});
+        // duplicates within one set only count once
+        let lists = vec![vec!["a", "a"], vec!["b"]];
+        assert_eq!(
+            EasySet::symmetric_difference_all(lists.clone()),
+            set! {"a", "b"}
+        );
+        assert_eq!(EasySet::appearing_exactly_once(lists), set! {"a", "b"});
+        assert_eq!(
+            EasySet::<u8>::symmetric_difference_all(Vec::<Vec<u8>>::new()),
+            set! {}
+        );
+    }
+
+    #[test]
+    fn membership_matrix() {
+        let sets = vec![set! {1, 2}, set! {2, 3}, set! {}];
+        let membership = membership(&sets);
+        assert_eq!(membership.len(), 3);
+        assert_eq!(membership[1], set! {0});
+        assert_eq!(membership[2], set! {0, 1});
+        assert_eq!(membership[3], set! {1});
+        assert!(super::membership::<u8>(&[]).is_empty());
+    }
+
+    #[test]
+    fn operands() {
+        let a = set! {1, 2, 3};
+        let hash: HashSet<_> = vec![3, 4].into_iter().collect();
+        assert_eq!(EasySet::from(&a), a);
+        assert_eq!(EasySet::from(&hash), set! {3, 4});
+
+        assert_eq!(&a & set! {3, 4}, set! {3});
+        assert_eq!(&a & &set! {3, 4}, set! {3});
+        assert_eq!(&a & hash.clone(), set! {3});
+        assert_eq!(&a & &hash, set! {3});
+        assert_eq!(&a & vec![3, 4, 4], set! {3});
+        assert_eq!(&a & &vec![3, 4], set! {3});
+        assert_eq!(&a & &[3, 4][..], set! {3});
+        assert_eq!(set! {'a', 'b'} & String::from("bc"), set! {'b'});
+
+        // a larger right hand side, and duplicates in the operand
+        let big = (2..100).collect::<Vec<_>>();
+        assert_eq!(&a & &big, set! {2, 3});
+        assert_eq!(&a ^ vec![3, 4, 4], set! {1, 2, 4});
+        let mut b = a.clone();
+        b ^= vec![3, 4, 4];
+        assert_eq!(b, set! {1, 2, 4});
+        b -= &hash;
+        assert_eq!(b, set! {1, 2});
+    }
 }
@@ -0,0 +1,242 @@
+use std::collections::btree_map::Range;
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+use std::ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds};
+
+#[macro_export]
+macro_rules! sorted_map {
+    () => {
+        $crate::EasySortedMap::new()
+    };
+    {$default:expr} => {
+        $crate::EasySortedMap::new_with_default($default)
+    };
+
+    {$($key:expr => $val:expr$(,)?)*} => {{
+        let mut map = $crate::sorted_map!{};
+        $(map.insert($key, $val);)*
+        map
+    }};
+    {$default:expr; $($key:expr => $val:expr$(,)?)*} => {{
+        let mut map = $crate::sorted_map!{$default};
+        $(map.insert($key, $val);)*
+        map
+    }};
+}
+
+/// A wrapper around `BTreeMap` that creates default values for empty keys, the same as `EasyMap`.
+/// Since its keys are sorted, it also supports range queries and nearest key lookups.
+///
+/// ```rust
+/// use easy_collections::sorted_map;
+///
+/// let mut map = sorted_map!{"none"; 0 => "low", 50 => "mid", 100 => "high"};
+/// assert_eq!(map[50], "mid");
+/// assert_eq!(map[51], "none");
+/// assert_eq!(map.floor_entry(&75), Some((&50, &"mid")));
+/// assert_eq!(map.ceiling_entry(&75), Some((&100, &"high")));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EasySortedMap<K: Ord, V> {
+    pub(crate) inner: BTreeMap<K, V>,
+    pub(crate) default: V,
+}
+
+impl<K: Ord, V: Default> EasySortedMap<K, V> {
+    /// Create a new `EasySortedMap`. The value `V` must implement `Default`.
+    ///
+    /// Note, that there are macros which make this easier:
+    /// ```rust
+    /// use easy_collections::sorted_map;
+    ///
+    /// let mut map = sorted_map!{};
+    /// map[1] = (10, 4);
+    /// let map = sorted_map!{"foo" => "bar", "hello" => "world"};
+    /// assert_eq!(map["not here"], "");
+    /// ```
+    pub fn new() -> EasySortedMap<K, V> {
+        EasySortedMap::new_with_default(V::default())
+    }
+}
+
+impl<K: Ord, V> EasySortedMap<K, V> {
+    /// Create a new `EasySortedMap`. The value `V` does not need to implement `Default`, instead you provide it with
+    /// one here.
+    ///
+    /// ```rust
+    /// use easy_collections::sorted_map;
+    ///
+    /// let map = sorted_map!{42; "foo" => 1};
+    /// assert_eq!(map["foo"], 1);
+    /// assert_eq!(map["nope"], 42);
+    /// ```
+    pub fn new_with_default(default: V) -> EasySortedMap<K, V> {
+        EasySortedMap {
+            inner: BTreeMap::new(),
+            default,
+        }
+    }
+
+    /// Returns the default value, which is returned when reading keys which aren't in the map.
+    pub fn default_value(&self) -> &V {
+        &self.default
+    }
+
+    /// Same as `BTreeMap::insert`.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.inner.insert(k, v)
+    }
+
+    /// Same as `BTreeMap::remove`.
+    pub fn remove(&mut self, k: K) -> Option<V> {
+        self.inner.remove(&k)
+    }
+
+    /// Returns an iterator over the entries in the range, in ascending order of their keys.
+    ///
+    /// ```rust
+    /// use easy_collections::sorted_map;
+    ///
+    /// let map = sorted_map!{1 => 'a', 2 => 'b', 3 => 'c'};
+    /// assert_eq!(map.range(2..).collect::<Vec<_>>(), vec![(&2, &'b'), (&3, &'c')]);
+    /// ```
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V> {
+        self.inner.range(range)
+    }
+
+    /// Returns the entry with the greatest key which is less than or equal to `k`.
+    ///
+    /// ```rust
+    /// use easy_collections::sorted_map;
+    ///
+    /// let map = sorted_map!{10 => 'a', 20 => 'b'};
+    /// assert_eq!(map.floor_entry(&15), Some((&10, &'a')));
+    /// assert_eq!(map.floor_entry(&20), Some((&20, &'b')));
+    /// assert_eq!(map.floor_entry(&5), None);
+    /// ```
+    pub fn floor_entry(&self, k: &K) -> Option<(&K, &V)> {
+        self.inner.range(..=k).next_back()
+    }
+
+    /// Returns the entry with the smallest key which is greater than or equal to `k`.
+    ///
+    /// ```rust
+    /// use easy_collections::sorted_map;
+    ///
+    /// let map = sorted_map!{10 => 'a', 20 => 'b'};
+    /// assert_eq!(map.ceiling_entry(&15), Some((&20, &'b')));
+    /// assert_eq!(map.ceiling_entry(&10), Some((&10, &'a')));
+    /// assert_eq!(map.ceiling_entry(&25), None);
+    /// ```
+    pub fn ceiling_entry(&self, k: &K) -> Option<(&K, &V)> {
+        self.inner
+            .range((Bound::Included(k), Bound::Unbounded))
+            .next()
+    }
+}
+
+impl<K: Ord, V: Default> Default for EasySortedMap<K, V> {
+    fn default() -> Self {
+        EasySortedMap::new()
+    }
+}
+
+impl<K: Ord, V: Default> From<Vec<(K, V)>> for EasySortedMap<K, V> {
+    fn from(v: Vec<(K, V)>) -> Self {
+        v.into_iter().collect()
+    }
+}
+
+impl<K: Ord, V: Default> FromIterator<(K, V)> for EasySortedMap<K, V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        EasySortedMap {
+            inner: iter.into_iter().collect(),
+            default: V::default(),
+        }
+    }
+}
+
+impl<K: Ord, V> IntoIterator for EasySortedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::collections::btree_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<K: Ord, V> Deref for EasySortedMap<K, V> {
+    type Target = BTreeMap<K, V>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K: Ord, V> DerefMut for EasySortedMap<K, V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<K: Ord, V> Index<K> for EasySortedMap<K, V> {
+    type Output = V;
+    fn index(&self, key: K) -> &Self::Output {
+        self.inner.get(&key).unwrap_or(&self.default)
+    }
+}
+impl<K: Ord, V: Clone> IndexMut<K> for EasySortedMap<K, V> {
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        self.inner.entry(key).or_insert(self.default.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn macros() {
+        let map: EasySortedMap<char, usize> = sorted_map! {};
+        assert_eq!(map['a'], 0);
+        let map = sorted_map! {1; 'b' => 2, 'a' => 3};
+        assert_eq!(map['c'], 1);
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            vec![('a', 3), ('b', 2)]
+        );
+    }
+
+    #[test]
+    fn index() {
+        let mut map = EasySortedMap::new_with_default(vec![0]);
+        map[3].push(1);
+        map[1] = vec![];
+        assert_eq!(map[3], vec![0, 1]);
+        assert_eq!(map[2], vec![0]);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![&1, &3]);
+    }
+
+    #[test]
+    fn nearest_keys() {
+        let map = (0..5).map(|n| (n * 10, n)).collect::<EasySortedMap<_, _>>();
+        assert_eq!(map.floor_entry(&-1), None);
+        assert_eq!(map.floor_entry(&0), Some((&0, &0)));
+        assert_eq!(map.floor_entry(&39), Some((&30, &3)));
+        assert_eq!(map.floor_entry(&100), Some((&40, &4)));
+        assert_eq!(map.ceiling_entry(&-1), Some((&0, &0)));
+        assert_eq!(map.ceiling_entry(&31), Some((&40, &4)));
+        assert_eq!(map.ceiling_entry(&41), None);
+
+        let empty = EasySortedMap::<u8, u8>::new();
+        assert_eq!(empty.floor_entry(&1), None);
+        assert_eq!(empty.ceiling_entry(&1), None);
+    }
+
+    #[test]
+    fn range() {
+        let map = sorted_map! {'a' => 1, 'b' => 2, 'c' => 3, 'd' => 4};
+        let values = map.range('b'..'d').map(|(_, v)| *v).collect::<Vec<_>>();
+        assert_eq!(values, vec![2, 3]);
+        assert_eq!(map.range(..'b').count(), 1);
+    }
+}
@@ -0,0 +1,359 @@
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    collections::{btree_set::Range, BTreeSet},
+    iter::FromIterator,
+    ops::{
+        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut,
+        RangeBounds, Sub, SubAssign,
+    },
+};
+
+use paste::paste;
+
+#[macro_export]
+macro_rules! sorted_set {
+    () => {
+        $crate::EasySortedSet::new()
+    };
+    {$($key:expr$(,)?)*} => {{
+        let mut set = $crate::sorted_set!{};
+        $(set.insert($key);)*
+        set
+    }};
+}
+
+/// A wrapper around `BTreeSet`, which keeps its items sorted. It implements the same operators as `EasySet` (see its
+/// documentation), and also supports range queries.
+///
+/// ```rust
+/// use easy_collections::sorted_set;
+///
+/// let set = sorted_set!{5, 1, 4, 2, 3};
+/// assert_eq!(set.first(), Some(&1));
+/// assert_eq!(set.last(), Some(&5));
+/// assert_eq!(set.range(2..4).collect::<Vec<_>>(), vec![&2, &3]);
+///
+/// let a = &sorted_set!{1, 2, 3};
+/// let b = &sorted_set!{2, 3, 4};
+/// assert_eq!(a & b, sorted_set!{2, 3});
+/// assert_eq!(a | b, sorted_set!{1, 2, 3, 4});
+/// assert_eq!(a ^ b, sorted_set!{1, 4});
+/// assert_eq!(a - b, sorted_set!{1});
+/// assert!(a < &(a | b));
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct EasySortedSet<K: Ord> {
+    pub(crate) inner: BTreeSet<K>,
+}
+
+impl<K: Ord> EasySortedSet<K> {
+    /// Create a new `EasySortedSet`.
+    ///
+    /// Note, there are macros to make this easier:
+    /// ```rust
+    /// use easy_collections::{EasySortedSet, sorted_set};
+    ///
+    /// // create an empty set
+    /// let set: EasySortedSet<usize> = sorted_set!{};
+    /// // create a set with values
+    /// let set = sorted_set!{'a', 'b', 'c', 'd'};
+    /// ```
+    pub fn new() -> EasySortedSet<K> {
+        EasySortedSet {
+            inner: BTreeSet::new(),
+        }
+    }
+
+    /// Same as `BTreeSet::insert`.
+    pub fn insert(&mut self, k: K) -> bool {
+        self.inner.insert(k)
+    }
+
+    /// Same as `BTreeSet::contains`.
+    pub fn contains(&self, k: &K) -> bool {
+        self.inner.contains(k)
+    }
+
+    /// Same as `BTreeSet::remove`.
+    pub fn remove(&mut self, k: &K) -> bool {
+        self.inner.remove(k)
+    }
+
+    /// Inserts every item from `iter` into the set, the same as Python's `set.update`.
+    pub fn update(&mut self, iter: impl IntoIterator<Item = K>) {
+        self.inner.extend(iter);
+    }
+
+    /// Returns the smallest item in the set, or `None` if it's empty.
+    pub fn first(&self) -> Option<&K> {
+        self.inner.first()
+    }
+
+    /// Returns the largest item in the set, or `None` if it's empty.
+    pub fn last(&self) -> Option<&K> {
+        self.inner.last()
+    }
+
+    /// Returns an iterator over the items in the range, in ascending order.
+    ///
+    /// ```rust
+    /// use easy_collections::sorted_set;
+    ///
+    /// let set = sorted_set!{1, 3, 5, 7, 9};
+    /// assert_eq!(set.range(3..=7).collect::<Vec<_>>(), vec![&3, &5, &7]);
+    /// assert_eq!(set.range(8..).collect::<Vec<_>>(), vec![&9]);
+    /// ```
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K> {
+        self.inner.range(range)
+    }
+
+    /// Splits the set in two at `k`, returning every item greater than or equal to `k`.
+    ///
+    /// ```rust
+    /// use easy_collections::sorted_set;
+    ///
+    /// let mut set = sorted_set!{1, 2, 3, 4};
+    /// let high = set.split_off(&3);
+    /// assert_eq!(set, sorted_set!{1, 2});
+    /// assert_eq!(high, sorted_set!{3, 4});
+    /// ```
+    pub fn split_off(&mut self, k: &K) -> EasySortedSet<K> {
+        self.inner.split_off(k).into()
+    }
+}
+
+impl<K: Ord> Default for EasySortedSet<K> {
+    fn default() -> Self {
+        EasySortedSet::new()
+    }
+}
+
+impl<K: Ord> From<Vec<K>> for EasySortedSet<K> {
+    fn from(v: Vec<K>) -> Self {
+        v.into_iter().collect()
+    }
+}
+
+impl<K: Ord + Clone> From<&[K]> for EasySortedSet<K> {
+    fn from(v: &[K]) -> Self {
+        v.iter().cloned().collect()
+    }
+}
+
+impl<K: Ord> FromIterator<K> for EasySortedSet<K> {
+    fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
+        EasySortedSet {
+            inner: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<K: Ord> Extend<K> for EasySortedSet<K> {
+    fn extend<T: IntoIterator<Item = K>>(&mut self, iter: T) {
+        self.inner.extend(iter);
+    }
+}
+
+impl<K: Ord> IntoIterator for EasySortedSet<K> {
+    type Item = K;
+    type IntoIter = std::collections::btree_set::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, K: Ord> IntoIterator for &'a EasySortedSet<K> {
+    type Item = &'a K;
+    type IntoIter = std::collections::btree_set::Iter<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<K: Ord> Deref for EasySortedSet<K> {
+    type Target = BTreeSet<K>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K: Ord> DerefMut for EasySortedSet<K> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<K: Ord> PartialOrd for EasySortedSet<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord> Ord for EasySortedSet<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.inner.is_subset(&other.inner) {
+            Ordering::Less
+        } else if self.inner.is_superset(&other.inner) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+impl<K: Ord> From<BTreeSet<K>> for EasySortedSet<K> {
+    fn from(inner: BTreeSet<K>) -> Self {
+        EasySortedSet { inner }
+    }
+}
+
+impl<K: Ord> From<EasySortedSet<K>> for BTreeSet<K> {
+    fn from(easy: EasySortedSet<K>) -> Self {
+        easy.inner
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($trait:ident, $method:ident, |$this:ident, $rhs:ident| $assign_borrowed:expr) => {
+        paste! {
+            impl<K: Ord + Clone, T: Into<EasySortedSet<K>>> $trait<T> for &EasySortedSet<K> {
+                type Output = EasySortedSet<K>;
+                fn $method(self, rhs: T) -> Self::Output {
+                    self.$method(&rhs.into())
+                }
+            }
+            impl<K: Ord + Clone, T: Into<EasySortedSet<K>>> $trait<T> for EasySortedSet<K> {
+                type Output = Self;
+                fn $method(self, rhs: T) -> Self::Output {
+                    (&self).$method(&rhs.into())
+                }
+            }
+            // assign
+            impl<K: Ord + Clone, T: Into<EasySortedSet<K>>> [<$trait Assign>]<T> for EasySortedSet<K> {
+                fn [<$method _assign>](&mut self, rhs: T) {
+                    self.[<$method _assign>](&rhs.into())
+                }
+            }
+            // borrowed sets are used directly, rather than being converted (and cloned)
+            impl_bit_op!(@borrowed $trait, $method, EasySortedSet<K>, |$this, $rhs| $assign_borrowed);
+            impl_bit_op!(@borrowed $trait, $method, BTreeSet<K>, |$this, $rhs| $assign_borrowed);
+        }
+    };
+    (@borrowed $trait:ident, $method:ident, $rhs_ty:ty, |$this:ident, $rhs:ident| $assign_borrowed:expr) => {
+        paste! {
+            impl<K: Ord + Clone> $trait<&$rhs_ty> for &EasySortedSet<K> {
+                type Output = EasySortedSet<K>;
+                fn $method(self, rhs: &$rhs_ty) -> Self::Output {
+                    <&BTreeSet<K> as $trait<&BTreeSet<K>>>::$method(&self.inner, rhs).into()
+                }
+            }
+            impl<K: Ord + Clone> $trait<&$rhs_ty> for EasySortedSet<K> {
+                type Output = Self;
+                fn $method(self, rhs: &$rhs_ty) -> Self::Output {
+                    (&self).$method(rhs)
+                }
+            }
+            impl<K: Ord + Clone> [<$trait Assign>]<&$rhs_ty> for EasySortedSet<K> {
+                fn [<$method _assign>](&mut self, $rhs: &$rhs_ty) {
+                    let $this = self;
+                    $assign_borrowed
+                }
+            }
+        }
+    };
+}
+
+impl_bit_op! {
+    BitAnd, bitand,
+    |this, rhs| this.inner.retain(|k| rhs.contains(k))
+}
+impl_bit_op! {
+    BitOr, bitor,
+    |this, rhs| this.inner.extend(rhs.iter().cloned())
+}
+impl_bit_op! {
+    BitXor, bitxor,
+    |this, rhs| for k in rhs.iter() {
+        if !this.inner.remove(k) {
+            this.inner.insert(k.clone());
+        }
+    }
+}
+impl_bit_op! {
+    Sub, sub,
+    |this, rhs| for k in rhs.iter() {
+        this.inner.remove(k);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn macros() {
+        let set: EasySortedSet<u8> = sorted_set! {};
+        assert!(set.is_empty());
+        let set = sorted_set! {3, 1, 2, 1};
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn first_last_range() {
+        let set = (1..=10).collect::<EasySortedSet<_>>();
+        assert_eq!(set.first(), Some(&1));
+        assert_eq!(set.last(), Some(&10));
+        assert_eq!(set.range(4..7).cloned().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(set.range(..=2).cloned().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(set.range(11..).count(), 0);
+
+        let empty = EasySortedSet::<u8>::new();
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last(), None);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut set = sorted_set! {1, 2, 3};
+        assert_eq!(set.split_off(&10), sorted_set! {});
+        assert_eq!(set.split_off(&0), sorted_set! {1, 2, 3});
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn cmp() {
+        let a = sorted_set! {1, 2};
+        let b = sorted_set! {1, 2, 3};
+        assert!(a < b);
+        assert!(b > a);
+        assert!(a == sorted_set! {2, 1});
+    }
+
+    #[test]
+    fn ops() {
+        let a = sorted_set! {1, 2, 3};
+        let b = sorted_set! {3, 4, 5};
+        let c = b.iter().cloned().collect::<BTreeSet<_>>();
+
+        assert_eq!(&a & &b, sorted_set! {3});
+        assert_eq!(&a | &c, sorted_set! {1, 2, 3, 4, 5});
+        assert_eq!(a.clone() ^ &b, sorted_set! {1, 2, 4, 5});
+        assert_eq!(a.clone() - vec![1, 3], sorted_set! {2});
+        assert_eq!(&a & [2, 3, 4].as_ref(), sorted_set! {2, 3});
+
+        let mut d = a.clone();
+        d &= &b;
+        assert_eq!(d, sorted_set! {3});
+        let mut d = a.clone();
+        d |= vec![9];
+        assert_eq!(d, sorted_set! {1, 2, 3, 9});
+        let mut d = a.clone();
+        d ^= &c;
+        assert_eq!(d, sorted_set! {1, 2, 4, 5});
+        let mut d = a;
+        d -= &b;
+        assert_eq!(d, sorted_set! {1, 2});
+    }
+}
@@ -0,0 +1,147 @@
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+
+#[macro_export]
+macro_rules! stack {
+    () => {
+        $crate::EasyStack::new()
+    };
+    [$($item:expr),+ $(,)?] => {
+        $crate::EasyStack::from(vec![$($item),+])
+    };
+}
+
+/// A last-in, first-out stack, wrapping a `Vec`. Items are pushed onto and popped off the top of the stack.
+///
+/// ```rust
+/// use easy_collections::stack;
+///
+/// // the last item is the top of the stack
+/// let mut stack = stack![1, 2];
+/// stack.push(3).push(4);
+/// assert_eq!(stack.peek(), Some(&4));
+/// assert_eq!(stack.pop(), Some(4));
+/// assert_eq!(stack.pop(), Some(3));
+/// assert_eq!(stack.len(), 2);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EasyStack<T> {
+    pub(crate) inner: Vec<T>,
+}
+
+impl<T> EasyStack<T> {
+    /// Create a new, empty `EasyStack`.
+    pub fn new() -> EasyStack<T> {
+        EasyStack { inner: Vec::new() }
+    }
+
+    /// Pushes an item onto the top of the stack, returning the stack so calls can be chained.
+    pub fn push(&mut self, item: T) -> &mut EasyStack<T> {
+        self.inner.push(item);
+        self
+    }
+
+    /// Removes the item from the top of the stack and returns it, or `None` if it's empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    /// Returns the item on the top of the stack, or `None` if it's empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.inner.last()
+    }
+
+    /// Returns whether the stack is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T> Default for EasyStack<T> {
+    fn default() -> Self {
+        EasyStack::new()
+    }
+}
+
+/// The last item of the `Vec` is the top of the stack.
+impl<T> From<Vec<T>> for EasyStack<T> {
+    fn from(inner: Vec<T>) -> Self {
+        EasyStack { inner }
+    }
+}
+
+impl<T> From<EasyStack<T>> for Vec<T> {
+    fn from(easy: EasyStack<T>) -> Self {
+        easy.inner
+    }
+}
+
+impl<T> FromIterator<T> for EasyStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        EasyStack {
+            inner: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<T> for EasyStack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+impl<T> Deref for EasyStack<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for EasyStack<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn macros() {
+        let stack: EasyStack<u8> = stack![];
+        assert!(stack.is_empty());
+        let stack = stack![1, 2, 3,];
+        assert_eq!(stack.peek(), Some(&3));
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut stack = EasyStack::new();
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.peek(), None);
+
+        stack.push('a').push('b');
+        stack.extend(vec!['c']);
+        let mut popped = vec![];
+        while let Some(c) = stack.pop() {
+            popped.push(c);
+        }
+        assert_eq!(popped, vec!['c', 'b', 'a']);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn dfs() {
+        let edges = [vec![1, 2], vec![3], vec![3], vec![]];
+        let mut seen = vec![];
+        let mut stack = stack![0];
+        while let Some(n) = stack.pop() {
+            if !seen.contains(&n) {
+                seen.push(n);
+                stack.extend(edges[n].iter().rev().cloned());
+            }
+        }
+        assert_eq!(seen, vec![0, 1, 3, 2]);
+    }
+}
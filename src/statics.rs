@@ -0,0 +1,80 @@
+/// Declares a global `EasyMap`, which is created the first time it's used. The body is the same as the `map!` macro's.
+///
+/// ```rust
+/// use easy_collections::{static_map, EasyMap};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Token {
+///     Ident,
+///     If,
+///     Else,
+/// }
+///
+/// static_map!(KEYWORDS: EasyMap<&str, Token> = {Token::Ident; "if" => Token::If, "else" => Token::Else});
+///
+/// assert_eq!(KEYWORDS["if"], Token::If);
+/// assert_eq!(KEYWORDS["foo"], Token::Ident);
+/// ```
+#[macro_export]
+macro_rules! static_map {
+    ($(#[$attr:meta])* $vis:vis $name:ident: $ty:ty = {$($body:tt)*}$(;)?) => {
+        $(#[$attr])*
+        $vis static $name: ::std::sync::LazyLock<$ty> =
+            ::std::sync::LazyLock::new(|| $crate::map!{$($body)*});
+    };
+}
+
+/// Declares a global `EasySet`, which is created the first time it's used. The body is the same as the `set!` macro's.
+///
+/// ```rust
+/// use easy_collections::{static_set, EasySet};
+///
+/// static_set!(pub VOWELS: EasySet<char> = {'a', 'e', 'i', 'o', 'u'});
+///
+/// assert!(VOWELS.contains(&'e'));
+/// assert_eq!(VOWELS.len(), 5);
+/// ```
+#[macro_export]
+macro_rules! static_set {
+    ($(#[$attr:meta])* $vis:vis $name:ident: $ty:ty = {$($body:tt)*}$(;)?) => {
+        $(#[$attr])*
+        $vis static $name: ::std::sync::LazyLock<$ty> =
+            ::std::sync::LazyLock::new(|| $crate::set!{$($body)*});
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EasyMap, EasySet};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn counted(n: u32) -> u32 {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        n
+    }
+
+    static_map!(
+        /// A counted map.
+        COUNTED: EasyMap<&str, u32> = {"a" => counted(1), "b" => counted(2)}
+    );
+    static_map!(EMPTY: EasyMap<u8, u8> = {});
+    static_set!(pub(crate) PRIMES: EasySet<u32> = {2, 3, 5, 7};);
+
+    #[test]
+    fn static_map() {
+        assert_eq!(COUNTED["a"], 1);
+        assert_eq!(COUNTED["b"], 2);
+        assert_eq!(COUNTED["c"], 0);
+        // only initialised once
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+        assert!(EMPTY.is_empty());
+    }
+
+    #[test]
+    fn static_set() {
+        assert!(PRIMES.contains(&5));
+        assert!(!PRIMES.contains(&4));
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::EasySet;
+
+/// The number of characters in each n-gram of an `EasySubstringIndex`.
+const GRAM: usize = 3;
+
+/// An index over a set of strings, which finds the strings containing a substring without searching every string.
+///
+/// Each string is split into its trigrams (each run of 3 characters), and a query only checks the strings which have
+/// every trigram of the needle. Needles shorter than 3 characters check every string.
+///
+/// ```rust
+/// use easy_collections::{set, EasySubstringIndex};
+///
+/// let index = EasySubstringIndex::new(set!{"user_name", "username", "session_user"});
+/// assert_eq!(index.containing("_user"), set!{"session_user"});
+/// assert_eq!(index.containing("user").len(), 3);
+/// assert!(index.containing("admin").is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EasySubstringIndex<S: AsRef<str>> {
+    strings: Vec<S>,
+    grams: HashMap<[char; GRAM], Vec<usize>>,
+}
+
+fn trigrams(s: &str) -> impl Iterator<Item = [char; GRAM]> {
+    let chars = s.chars().collect::<Vec<_>>();
+    (0..chars.len().saturating_sub(GRAM - 1)).map(move |i| [chars[i], chars[i + 1], chars[i + 2]])
+}
+
+impl<S: AsRef<str>> EasySubstringIndex<S> {
+    /// Create a new `EasySubstringIndex` over the strings in `iter`.
+    pub fn new(iter: impl IntoIterator<Item = S>) -> EasySubstringIndex<S> {
+        let strings = iter.into_iter().collect::<Vec<_>>();
+        let mut grams = HashMap::<_, Vec<_>>::new();
+        for (i, s) in strings.iter().enumerate() {
+            for gram in trigrams(s.as_ref()) {
+                let ids = grams.entry(gram).or_default();
+                // strings are indexed in order, so a repeated trigram is always at the end
+                if ids.last() != Some(&i) {
+                    ids.push(i);
+                }
+            }
+        }
+
+        EasySubstringIndex { strings, grams }
+    }
+
+    /// Returns the number of strings in the index.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns whether the index has no strings.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Returns the strings in the index which contain `needle`.
+    pub fn containing(&self, needle: &str) -> EasySet<&str> {
+        let mut lists = vec![];
+        for gram in trigrams(needle) {
+            match self.grams.get(&gram) {
+                Some(ids) => lists.push(ids),
+                None => return EasySet::new(),
+            }
+        }
+
+        let candidates = match lists.iter().min_by_key(|ids| ids.len()) {
+            Some(shortest) => shortest.to_vec(),
+            None => (0..self.strings.len()).collect(),
+        };
+
+        candidates
+            .into_iter()
+            .filter(|i| lists.iter().all(|ids| ids.binary_search(i).is_ok()))
+            .map(|i| self.strings[i].as_ref())
+            .filter(|s| s.contains(needle))
+            .collect()
+    }
+}
+
+impl<S: AsRef<str> + Eq + Hash> From<EasySet<S>> for EasySubstringIndex<S> {
+    fn from(set: EasySet<S>) -> Self {
+        EasySubstringIndex::new(set)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::set;
+
+    #[test]
+    fn containing() {
+        let set = set! {
+            "abcabc".to_string(),
+            "xabcx".to_string(),
+            "ab".to_string(),
+            "añb".to_string(),
+            "".to_string(),
+        };
+        let index = EasySubstringIndex::from(set);
+        assert_eq!(index.len(), 5);
+        assert!(!index.is_empty());
+        assert_eq!(index.containing("abc"), set! {"abcabc", "xabcx"});
+        assert_eq!(index.containing("cab"), set! {"abcabc"});
+        assert_eq!(index.containing("abca"), set! {"abcabc"});
+        assert_eq!(index.containing("ab").len(), 3);
+        assert_eq!(index.containing("ñ"), set! {"añb"});
+        assert_eq!(index.containing("").len(), 5);
+        assert!(index.containing("zzz").is_empty());
+        // every trigram is present, but not in this order
+        assert!(index.containing("bcab_").is_empty());
+        assert!(index.containing("bcabca").is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+use std::hash::Hash;
+use std::ops::{Deref, Index, IndexMut};
+
+use crate::EasyMap;
+
+/// A handle for changing an `EasyMap` inside `EasyMap::transaction`. It can be read like the map itself, and every
+/// change made through it is undone if the transaction fails.
+pub struct EasyTransaction<'a, K: Eq + Hash + Clone, V: Clone> {
+    map: &'a mut EasyMap<K, V>,
+    /// The previous values of the changed keys, in the order they were changed.
+    undo: Vec<(K, Option<V>)>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> EasyMap<K, V> {
+    /// Calls `f` with a transaction for changing the map. If `f` returns `Ok`, its changes are kept, and if it returns
+    /// `Err` (or panics), they're all undone. This is cheaper than cloning the map to be able to go back to it, since only the
+    /// changed entries are copied.
+    ///
+    /// ```rust
+    /// use easy_collections::map;
+    ///
+    /// let mut stock = map!{"apples" => 3, "pears" => 1};
+    /// let mut buy = |order: &[&'static str]| {
+    ///     stock.transaction(|txn| {
+    ///         for item in order {
+    ///             if txn[*item] == 0 {
+    ///                 return Err(*item);
+    ///             }
+    ///             txn[*item] -= 1;
+    ///         }
+    ///         Ok(())
+    ///     })
+    /// };
+    /// assert_eq!(buy(&["apples", "pears"]), Ok(()));
+    /// assert_eq!(buy(&["apples", "pears"]), Err("pears"));
+    /// assert_eq!(stock, map!{"apples" => 2, "pears" => 0});
+    /// ```
+    pub fn transaction<R, E, F>(&mut self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut EasyTransaction<'_, K, V>) -> Result<R, E>,
+    {
+        let mut txn = EasyTransaction {
+            map: self,
+            undo: Vec::new(),
+        };
+        // dropping the transaction undoes its changes, which also happens if `f` panics
+        let result = f(&mut txn);
+        if result.is_ok() {
+            txn.undo.clear();
+        }
+
+        result
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> EasyTransaction<'_, K, V> {
+    /// Records `k`'s current value, so the change which is about to happen can be undone.
+    fn record(&mut self, k: &K) {
+        self.undo.push((k.clone(), self.map.get(k).cloned()));
+    }
+
+    /// Undoes every change, most recent first.
+    fn rollback(&mut self) {
+        while let Some((k, v)) = self.undo.pop() {
+            match v {
+                Some(v) => self.map.insert(k, v),
+                None => self.map.remove(k),
+            };
+        }
+    }
+
+    /// Same as `HashMap::insert`.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.record(&k);
+        self.map.insert(k, v)
+    }
+
+    /// Same as `HashMap::remove`.
+    pub fn remove(&mut self, k: K) -> Option<V> {
+        if !self.map.contains_key(&k) {
+            return None;
+        }
+
+        self.record(&k);
+        self.map.remove(k)
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Drop for EasyTransaction<'_, K, V> {
+    fn drop(&mut self) {
+        self.rollback();
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Deref for EasyTransaction<'_, K, V> {
+    type Target = EasyMap<K, V>;
+    fn deref(&self) -> &Self::Target {
+        self.map
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Index<K> for EasyTransaction<'_, K, V> {
+    type Output = V;
+    fn index(&self, key: K) -> &Self::Output {
+        &self.map[key]
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> IndexMut<K> for EasyTransaction<'_, K, V> {
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        self.record(&key);
+        &mut self.map[key]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::map;
+
+    #[test]
+    fn commit() {
+        let mut map = map! {0; 'a' => 1};
+        let result = map.transaction(|txn| {
+            txn['a'] += 1;
+            txn.insert('b', 2);
+            assert_eq!(txn.len(), 2);
+            Ok::<_, ()>(txn['a'])
+        });
+        assert_eq!(result, Ok(2));
+        assert_eq!(map, map! {0; 'a' => 2, 'b' => 2});
+    }
+
+    #[test]
+    fn rollback() {
+        let original = map! {vec![]; 1 => vec!['a'], 2 => vec!['b']};
+        let mut map = original.clone();
+        let result: Result<(), &str> = map.transaction(|txn| {
+            txn[1].push('c');
+            txn[1].push('d');
+            txn[3].push('e');
+            txn.remove(2);
+            txn.remove(4);
+            txn.insert(2, vec!['f']);
+            Err("nope")
+        });
+        assert_eq!(result, Err("nope"));
+        assert_eq!(map, original);
+    }
+
+    #[test]
+    fn rollback_on_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let original = map! {1 => 'a'};
+        let mut map = original.clone();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            map.transaction(|txn| {
+                txn[1] = 'b';
+                txn.insert(2, 'c');
+                if txn.len() == 2 {
+                    panic!("oops");
+                }
+                Ok::<_, ()>(())
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(map, original);
+    }
+}
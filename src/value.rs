@@ -0,0 +1,304 @@
+use std::convert::TryFrom;
+use std::ops::{Index, IndexMut};
+
+use crate::{EasyMap, NestedValue};
+
+static NULL: EasyValue = EasyValue::Null;
+
+/// A dynamically typed, JSON-like value, so data of different types can live in one `EasyMap<String, EasyValue>`.
+///
+/// Values can be created from most primitive types with `From`, and read back with the `as_*` methods or `TryFrom`.
+/// Indexing with a `&str` or a `usize` reads from maps and arrays, returning `EasyValue::Null` if the value isn't
+/// there, and writing by index creates the map or array if needed.
+///
+/// ```rust
+/// use easy_collections::{EasyMap, EasyValue};
+///
+/// let mut user = EasyValue::Null;
+/// user["name"] = "alice".into();
+/// user["age"] = 30.into();
+/// user["tags"][1] = "admin".into();
+/// assert_eq!(user["name"].as_str(), Some("alice"));
+/// assert_eq!(user["age"].as_i64(), Some(30));
+/// assert_eq!(user["tags"], EasyValue::from(vec![EasyValue::Null, "admin".into()]));
+/// assert!(user["email"].is_null());
+///
+/// let mut config: EasyMap<String, EasyValue> = EasyMap::new();
+/// config["server.port"] = 8080.into();
+/// assert_eq!(config["server"]["port"], 8080.into());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum EasyValue {
+    #[default]
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Array(Vec<EasyValue>),
+    /// An `EasyMap` holds its default value inline, so it's boxed here.
+    Map(Box<EasyMap<String, EasyValue>>),
+}
+
+impl EasyValue {
+    /// Returns whether the value is `EasyValue::Null`.
+    pub fn is_null(&self) -> bool {
+        *self == EasyValue::Null
+    }
+
+    /// Returns the value if it's a `bool`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            EasyValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's an integer.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            EasyValue::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's a number, converting integers to floats.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            EasyValue::Int(n) => Some(*n as f64),
+            EasyValue::Float(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            EasyValue::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's an array.
+    pub fn as_array(&self) -> Option<&Vec<EasyValue>> {
+        match self {
+            EasyValue::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Returns the value mutably if it's an array.
+    pub fn as_array_mut(&mut self) -> Option<&mut Vec<EasyValue>> {
+        match self {
+            EasyValue::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's a map.
+    pub fn as_map(&self) -> Option<&EasyMap<String, EasyValue>> {
+        match self {
+            EasyValue::Map(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Returns the value mutably if it's a map.
+    pub fn as_map_mut(&mut self) -> Option<&mut EasyMap<String, EasyValue>> {
+        match self {
+            EasyValue::Map(map) => Some(map),
+            _ => None,
+        }
+    }
+}
+
+impl NestedValue<String> for EasyValue {
+    fn as_map(&self) -> Option<&EasyMap<String, EasyValue>> {
+        EasyValue::as_map(self)
+    }
+
+    fn as_map_mut(&mut self) -> Option<&mut EasyMap<String, EasyValue>> {
+        EasyValue::as_map_mut(self)
+    }
+
+    fn new_map() -> EasyValue {
+        EasyValue::Map(Box::default())
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $($ty:ty),+) => {
+        $(
+            impl From<$ty> for EasyValue {
+                fn from(v: $ty) -> Self {
+                    EasyValue::$variant(v.into())
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(Bool, bool);
+impl_from!(Int, i8, i16, i32, i64, u8, u16, u32);
+impl_from!(Float, f32, f64);
+impl_from!(Str, &str, String, char);
+
+impl<T: Into<EasyValue>> From<Vec<T>> for EasyValue {
+    fn from(v: Vec<T>) -> Self {
+        EasyValue::Array(v.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<EasyMap<String, EasyValue>> for EasyValue {
+    fn from(map: EasyMap<String, EasyValue>) -> Self {
+        EasyValue::Map(Box::new(map))
+    }
+}
+
+impl<T: Into<EasyValue>> From<Option<T>> for EasyValue {
+    fn from(v: Option<T>) -> Self {
+        v.map_or(EasyValue::Null, Into::into)
+    }
+}
+
+macro_rules! impl_try_from {
+    ($variant:ident, $ty:ty) => {
+        /// Returns the value which failed to convert as the error.
+        impl TryFrom<EasyValue> for $ty {
+            type Error = EasyValue;
+            fn try_from(value: EasyValue) -> Result<Self, Self::Error> {
+                match value {
+                    EasyValue::$variant(v) => Ok(v),
+                    value => Err(value),
+                }
+            }
+        }
+    };
+}
+
+impl_try_from!(Bool, bool);
+impl_try_from!(Int, i64);
+impl_try_from!(Float, f64);
+impl_try_from!(Str, String);
+impl_try_from!(Array, Vec<EasyValue>);
+
+/// Returns `EasyValue::Null` if the value isn't a map, or if the key isn't in it.
+impl Index<&str> for EasyValue {
+    type Output = EasyValue;
+    fn index(&self, key: &str) -> &Self::Output {
+        match self {
+            EasyValue::Map(map) => map.get(key).unwrap_or(&NULL),
+            _ => &NULL,
+        }
+    }
+}
+
+/// Replaces the value with an empty map if it isn't one, and inserts `EasyValue::Null` if the key isn't in it.
+impl IndexMut<&str> for EasyValue {
+    fn index_mut(&mut self, key: &str) -> &mut Self::Output {
+        if self.as_map().is_none() {
+            *self = EasyValue::new_map();
+        }
+
+        self.as_map_mut()
+            .unwrap()
+            .entry(key.to_string())
+            .or_default()
+    }
+}
+
+/// Returns `EasyValue::Null` if the value isn't an array, or if the index is out of bounds.
+impl Index<usize> for EasyValue {
+    type Output = EasyValue;
+    fn index(&self, index: usize) -> &Self::Output {
+        match self {
+            EasyValue::Array(array) => array.get(index).unwrap_or(&NULL),
+            _ => &NULL,
+        }
+    }
+}
+
+/// Replaces the value with an empty array if it isn't one, and pads the array with `EasyValue::Null` if the index is
+/// out of bounds.
+impl IndexMut<usize> for EasyValue {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if self.as_array().is_none() {
+            *self = EasyValue::Array(vec![]);
+        }
+
+        let array = self.as_array_mut().unwrap();
+        if array.len() <= index {
+            array.resize(index + 1, EasyValue::Null);
+        }
+
+        &mut array[index]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::map;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(EasyValue::from(true).as_bool(), Some(true));
+        assert_eq!(EasyValue::from(7_u8).as_i64(), Some(7));
+        assert_eq!(EasyValue::from(7).as_f64(), Some(7.0));
+        assert_eq!(EasyValue::from(0.5).as_f64(), Some(0.5));
+        assert_eq!(EasyValue::from(0.5).as_i64(), None);
+        assert_eq!(EasyValue::from('x').as_str(), Some("x"));
+        assert_eq!(EasyValue::from(None::<u8>), EasyValue::Null);
+        assert_eq!(EasyValue::from(Some("a")), EasyValue::from("a"));
+        assert_eq!(
+            EasyValue::from(vec![1, 2]).as_array(),
+            Some(&vec![EasyValue::Int(1), EasyValue::Int(2)])
+        );
+        assert_eq!(EasyValue::default(), EasyValue::Null);
+
+        let map: EasyMap<String, EasyValue> = map! {"a".to_string() => 1.into()};
+        assert_eq!(EasyValue::from(map.clone()).as_map(), Some(&map));
+
+        assert_eq!(i64::try_from(EasyValue::Int(3)), Ok(3));
+        assert_eq!(String::try_from(EasyValue::from("s")), Ok("s".to_string()));
+        assert_eq!(bool::try_from(EasyValue::Int(3)), Err(EasyValue::Int(3)));
+    }
+
+    #[test]
+    fn index() {
+        let mut value = EasyValue::from(1);
+        assert!(value["a"].is_null());
+        assert!(value[0].is_null());
+
+        value["a"]["b"] = true.into();
+        assert_eq!(value["a"]["b"], EasyValue::Bool(true));
+        assert!(value["a"]["c"].is_null());
+
+        value["list"][2] = "c".into();
+        value["list"][0] = "a".into();
+        assert_eq!(
+            value["list"],
+            EasyValue::from(vec!["a".into(), EasyValue::Null, "c".into()])
+        );
+        assert!(value["list"][5].is_null());
+
+        // writing by a key into an array replaces it with a map
+        value["list"]["x"] = 1.into();
+        assert_eq!(value["list"]["x"], 1.into());
+        assert!(value["list"][0].is_null());
+    }
+
+    #[test]
+    fn nested() {
+        let mut config: EasyMap<String, EasyValue> = EasyMap::new();
+        config["db.host"] = "localhost".into();
+        config["db.port"] = 5432.into();
+        assert_eq!(config["db"]["host"].as_str(), Some("localhost"));
+        assert_eq!(config["db.port"].as_i64(), Some(5432));
+        assert!(config["db.user"].is_null());
+        assert_eq!(
+            config.get_path(["db", "port"].map(String::from)),
+            Some(&EasyValue::Int(5432))
+        );
+    }
+}
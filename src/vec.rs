@@ -0,0 +1,238 @@
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+
+#[macro_export]
+macro_rules! vec_easy {
+    () => {
+        $crate::EasyVec::new()
+    };
+    [$($item:expr),+ $(,)?] => {
+        $crate::EasyVec::from(vec![$($item),+])
+    };
+    [$default:expr;] => {
+        $crate::EasyVec::new_with_default($default)
+    };
+    [$default:expr; $($item:expr),+ $(,)?] => {{
+        let mut v = $crate::EasyVec::new_with_default($default);
+        $(v.push($item);)*
+        v
+    }};
+}
+
+/// A wrapper around `Vec` which supports negative indexes (counting from the back, like Python's lists), and which
+/// returns a default value when reading out of bounds instead of panicking.
+///
+/// ```rust
+/// use easy_collections::vec_easy;
+///
+/// let v = vec_easy![1, 2, 3];
+/// assert_eq!(v[0], 1);
+/// assert_eq!(v[-1], 3);
+/// assert_eq!(v[-3], 1);
+/// assert_eq!(v[10], 0);
+/// assert_eq!(v[-10], 0);
+///
+/// let v = vec_easy!['?'; 'a', 'b'];
+/// assert_eq!(v[2], '?');
+/// ```
+///
+/// Writing past the end grows the `Vec`, filling any gaps with the default value. Writing before the start panics, and
+/// so does writing so far past the end that the `Vec` can't be allocated.
+/// ```rust
+/// use easy_collections::vec_easy;
+///
+/// let mut v = vec_easy![1];
+/// v[3] = 4;
+/// assert_eq!(*v, vec![1, 0, 0, 4]);
+/// v[-1] += 1;
+/// assert_eq!(v[3], 5);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EasyVec<T: Clone> {
+    pub(crate) inner: Vec<T>,
+    pub(crate) default: T,
+}
+
+impl<T: Clone + Default> EasyVec<T> {
+    /// Create a new `EasyVec`. The value `T` must implement `Default`.
+    pub fn new() -> EasyVec<T> {
+        EasyVec::new_with_default(T::default())
+    }
+}
+
+impl<T: Clone> EasyVec<T> {
+    /// Create a new `EasyVec`, with a default value which is returned when reading out of bounds.
+    pub fn new_with_default(default: T) -> EasyVec<T> {
+        EasyVec {
+            inner: Vec::new(),
+            default,
+        }
+    }
+
+    /// Returns the default value, which is returned when reading out of bounds.
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Returns the `EasyVec` with its default value changed.
+    pub fn with_default(mut self, default: T) -> EasyVec<T> {
+        self.default = default;
+        self
+    }
+
+    /// Converts a (possibly negative) index into a position in the `Vec`, or `None` if it's before the start.
+    fn position(&self, index: isize) -> Option<usize> {
+        if index < 0 {
+            self.inner.len().checked_sub(index.unsigned_abs())
+        } else {
+            Some(index as usize)
+        }
+    }
+}
+
+impl<T: Clone + Default> Default for EasyVec<T> {
+    fn default() -> Self {
+        EasyVec::new()
+    }
+}
+
+impl<T: Clone + Default> From<Vec<T>> for EasyVec<T> {
+    fn from(inner: Vec<T>) -> Self {
+        EasyVec {
+            inner,
+            default: T::default(),
+        }
+    }
+}
+
+impl<T: Clone> From<EasyVec<T>> for Vec<T> {
+    fn from(easy: EasyVec<T>) -> Self {
+        easy.inner
+    }
+}
+
+impl<T: Clone + Default> FromIterator<T> for EasyVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
+
+impl<T: Clone> Extend<T> for EasyVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+impl<T: Clone> IntoIterator for EasyVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<T: Clone> Deref for EasyVec<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: Clone> DerefMut for EasyVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: Clone> Index<isize> for EasyVec<T> {
+    type Output = T;
+    fn index(&self, index: isize) -> &Self::Output {
+        self.position(index)
+            .and_then(|i| self.inner.get(i))
+            .unwrap_or(&self.default)
+    }
+}
+impl<T: Clone> IndexMut<isize> for EasyVec<T> {
+    fn index_mut(&mut self, index: isize) -> &mut Self::Output {
+        let len = self.inner.len();
+        let i = match self.position(index) {
+            Some(i) => i,
+            None => panic!(
+                "index {} is out of bounds for an EasyVec of length {}",
+                index, len
+            ),
+        };
+        if i >= len {
+            if self.inner.try_reserve_exact(i + 1 - len).is_err() {
+                panic!(
+                    "index {} is too large to grow an EasyVec of length {}",
+                    index, len
+                );
+            }
+            self.inner.resize(i + 1, self.default.clone());
+        }
+
+        &mut self.inner[i]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn macros() {
+        let v: EasyVec<u8> = vec_easy![];
+        assert!(v.is_empty());
+        let v = vec_easy![1, 2, 3,];
+        assert_eq!(*v, vec![1, 2, 3]);
+        let v: EasyVec<u8> = vec_easy![7;];
+        assert_eq!(v[0], 7);
+        let v = vec_easy!["-"; "a"];
+        assert_eq!(v[0], "a");
+        assert_eq!(v[1], "-");
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct NoDefault(u8);
+        let v = vec_easy![NoDefault(0); NoDefault(1)];
+        assert_eq!(v[0], NoDefault(1));
+        assert_eq!(v[1], NoDefault(0));
+    }
+
+    #[test]
+    fn index() {
+        let v = (1..=5).collect::<EasyVec<_>>();
+        assert_eq!(v[0], 1);
+        assert_eq!(v[4], 5);
+        assert_eq!(v[5], 0);
+        assert_eq!(v[-1], 5);
+        assert_eq!(v[-5], 1);
+        assert_eq!(v[-6], 0);
+        assert_eq!(v[isize::MIN], 0);
+        assert_eq!(v[isize::MAX], 0);
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut v = EasyVec::new_with_default(vec!['x']);
+        v[1].push('y');
+        assert_eq!(*v, vec![vec!['x'], vec!['x', 'y']]);
+        v[-2].clear();
+        assert!(v[0].is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "index -2 is out of bounds for an EasyVec of length 1")]
+    fn index_mut_before_start() {
+        let mut v = vec_easy![1];
+        v[-2] = 2;
+    }
+
+    #[test]
+    #[should_panic(expected = "is too large to grow an EasyVec of length 1")]
+    fn index_mut_too_large() {
+        let mut v = vec_easy![1_u8];
+        v[isize::MAX] = 2;
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{EasyMap, EasySet};
+use js_sys::{Map, Set};
+use std::convert::TryFrom;
+use std::hash::Hash;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Converts a `JsValue`, returning the original value as the error if it couldn't be converted.
+fn try_from_js<T: TryFrom<JsValue>>(value: JsValue) -> Result<T, JsValue> {
+    T::try_from(value.clone()).map_err(|_| value)
+}
+
+/// Converting back from JavaScript is fallible, since the entries may not have the right types.
+///
+/// ```rust,no_run
+/// use easy_collections::{map, EasyMap};
+/// use js_sys::Map;
+/// use std::convert::TryFrom;
+/// use wasm_bindgen::JsValue;
+///
+/// let easy: EasyMap<String, f64> = map!{"a".to_string() => 1.0};
+/// let js = Map::from(easy.clone());
+/// assert_eq!(EasyMap::try_from(js), Ok(easy.clone()));
+///
+/// let value = JsValue::from(easy.clone());
+/// assert_eq!(EasyMap::try_from(value), Ok(easy));
+/// assert!(EasyMap::<String, f64>::try_from(JsValue::from("not a map")).is_err());
+/// ```
+impl<K: Eq + Hash + Into<JsValue>, V: Into<JsValue>> From<EasyMap<K, V>> for Map {
+    fn from(easy: EasyMap<K, V>) -> Self {
+        let map = Map::new();
+        for (k, v) in easy {
+            map.set(&k.into(), &v.into());
+        }
+
+        map
+    }
+}
+
+impl<K: Eq + Hash + Into<JsValue>, V: Into<JsValue>> From<EasyMap<K, V>> for JsValue {
+    fn from(easy: EasyMap<K, V>) -> Self {
+        Map::from(easy).into()
+    }
+}
+
+impl<K, V> TryFrom<Map> for EasyMap<K, V>
+where
+    K: Eq + Hash + TryFrom<JsValue>,
+    V: Default + TryFrom<JsValue>,
+{
+    type Error = JsValue;
+
+    fn try_from(map: Map) -> Result<Self, Self::Error> {
+        let mut easy = EasyMap::new();
+        let mut result = Ok(());
+        map.for_each(&mut |v, k| {
+            if result.is_ok() {
+                result = try_from_js(k).and_then(|k| {
+                    easy.insert(k, try_from_js(v)?);
+                    Ok(())
+                });
+            }
+        });
+
+        result.map(|_| easy)
+    }
+}
+
+impl<K, V> TryFrom<JsValue> for EasyMap<K, V>
+where
+    K: Eq + Hash + TryFrom<JsValue>,
+    V: Default + TryFrom<JsValue>,
+{
+    type Error = JsValue;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        EasyMap::try_from(value.dyn_into::<Map>()?)
+    }
+}
+
+/// ```rust,no_run
+/// use easy_collections::{set, EasySet};
+/// use js_sys::Set;
+/// use std::convert::TryFrom;
+/// use wasm_bindgen::JsValue;
+///
+/// let easy: EasySet<String> = set!{"a".to_string(), "b".to_string()};
+/// let js = Set::from(easy.clone());
+/// assert_eq!(js.size(), 2);
+/// assert_eq!(EasySet::try_from(js), Ok(easy.clone()));
+///
+/// let value = JsValue::from(easy.clone());
+/// assert_eq!(EasySet::try_from(value), Ok(easy));
+/// ```
+impl<K: Eq + Hash + Into<JsValue>> From<EasySet<K>> for Set {
+    fn from(easy: EasySet<K>) -> Self {
+        let set = Set::new(&JsValue::UNDEFINED);
+        for k in easy {
+            set.add(&k.into());
+        }
+
+        set
+    }
+}
+
+impl<K: Eq + Hash + Into<JsValue>> From<EasySet<K>> for JsValue {
+    fn from(easy: EasySet<K>) -> Self {
+        Set::from(easy).into()
+    }
+}
+
+impl<K: Eq + Hash + TryFrom<JsValue>> TryFrom<Set> for EasySet<K> {
+    type Error = JsValue;
+
+    fn try_from(set: Set) -> Result<Self, Self::Error> {
+        let mut easy = EasySet::new();
+        let mut result = Ok(());
+        set.for_each(&mut |k, _, _| {
+            if result.is_ok() {
+                result = try_from_js(k).map(|k| {
+                    easy.insert(k);
+                });
+            }
+        });
+
+        result.map(|_| easy)
+    }
+}
+
+impl<K: Eq + Hash + TryFrom<JsValue>> TryFrom<JsValue> for EasySet<K> {
+    type Error = JsValue;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        EasySet::try_from(value.dyn_into::<Set>()?)
+    }
+}
@@ -0,0 +1,94 @@
+//! A serde format for `EasyMap` which preserves its default value, for use with `#[serde(with = "...")]`.
+//!
+//! Maps are serialized as `{"default": ..., "entries": {...}}`, so round-tripping keeps its behaviour for missing keys.
+//!
+//! ```rust
+//! use easy_collections::{map, EasyMap};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct State {
+//!     #[serde(with = "easy_collections::with_default")]
+//!     scores: EasyMap<String, i32>,
+//! }
+//!
+//! let state = State { scores: map!{-1; "foo".to_string() => 10} };
+//! let json = serde_json::to_string(&state).unwrap();
+//! assert_eq!(json, r#"{"scores":{"default":-1,"entries":{"foo":10}}}"#);
+//!
+//! let state: State = serde_json::from_str(&json).unwrap();
+//! assert_eq!(state.scores["foo".to_string()], 10);
+//! assert_eq!(state.scores["bar".to_string()], -1);
+//! ```
+
+use crate::map::Fill;
+use crate::EasyMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Serialize)]
+struct SerializeRepr<'a, K: Eq + Hash, V> {
+    default: &'a V,
+    entries: &'a HashMap<K, V>,
+}
+
+#[derive(Deserialize)]
+struct DeserializeRepr<K: Eq + Hash, V> {
+    default: V,
+    entries: HashMap<K, V>,
+}
+
+/// Serializes an `EasyMap` along with its default value.
+pub fn serialize<K, V, S>(map: &EasyMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Eq + Hash + Serialize,
+    V: Serialize,
+    S: Serializer,
+{
+    SerializeRepr {
+        default: &map.default,
+        entries: &map.inner,
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes an `EasyMap` along with its default value.
+pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<EasyMap<K, V>, D::Error>
+where
+    K: Eq + Hash + Deserialize<'de>,
+    V: Clone + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let repr = DeserializeRepr::deserialize(deserializer)?;
+    Ok(EasyMap {
+        inner: repr.entries,
+        default: repr.default,
+        fill: Fill::Value(V::clone),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::map;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super")] EasyMap<char, Vec<u8>>);
+
+    #[test]
+    fn round_trip() {
+        let wrapper = Wrapper(map! {vec![1]; 'a' => vec![2, 3]});
+        let json = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(json, r#"{"default":[1],"entries":{"a":[2,3]}}"#);
+
+        let restored: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, wrapper);
+        assert_eq!(restored.0['b'], vec![1]);
+    }
+
+    #[test]
+    fn missing_default() {
+        assert!(serde_json::from_str::<Wrapper>(r#"{"entries":{}}"#).is_err());
+    }
+}